@@ -1,14 +1,31 @@
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+#[cfg(target_os="macos")]
 use std::process::Command;
 use std::fs;
 use std::io;
-use ureq;
-use zip;
-/// Since the build.rs is supposed to run on the host machine, cross-compiling this package on a single host machine will lead to unexpected results.
+use sha2::{Digest, Sha256};
+// Since the build.rs is supposed to run on the host machine, cross-compiling this package on a single host machine will lead to unexpected results.
 
-/// Targets = x86_64-apple-darwin, aarch64-apple-darwin, aarch64-unknown-linux-gnu, x86_64-pc-windows-msvc, x86_64-unknown-linux-gnu,
-/// x86_64-unknown-linux-musl, aarch64-unknown-linux-musl
+// Targets = x86_64-apple-darwin, aarch64-apple-darwin, aarch64-unknown-linux-gnu, x86_64-pc-windows-msvc, x86_64-unknown-linux-gnu,
+// x86_64-unknown-linux-musl, aarch64-unknown-linux-musl
+
+/// Offline builds can skip the download by pointing one of these variables at a local copy of the library.
+///
+/// `LEXACTIVATOR_LIB_DIR` - directory that already contains the extracted static library for the target.
+/// `LEXACTIVATOR_ARCHIVE` - path to a pre-downloaded LexActivator archive (e.g. LexActivator-Static-Linux.zip).
+const LIB_DIR_ENV: &str = "LEXACTIVATOR_LIB_DIR";
+const ARCHIVE_ENV: &str = "LEXACTIVATOR_ARCHIVE";
+
+/// Overrides the pinned SHA-256 digest below, e.g. for custom builds of the LexActivator archive.
+const ARCHIVE_SHA256_ENV: &str = "LEXACTIVATOR_ARCHIVE_SHA256";
+
+/// Pinned SHA-256 digests of the LexActivator archives as (version, zip_filename, sha256).
+///
+/// Every archive is verified against its digest before extraction, so an entry must be added here for each
+/// archive whenever `version` is bumped.
+const ARCHIVE_SHA256: &[(&str, &str, &str)] = &[
+];
 
 fn download_and_setup_libs() -> Result<(), Box<dyn std::error::Error>> {
     let out_dir = env::var("OUT_DIR")?;
@@ -63,6 +80,36 @@ fn download_and_setup_libs() -> Result<(), Box<dyn std::error::Error>> {
     
     #[cfg(not(target_os="windows"))]
     let lib_file = target_dir.join("libLexActivator.a");
+    let lib_filename = lib_file.file_name().unwrap().to_string_lossy().into_owned();
+
+    println!("cargo:rerun-if-env-changed={}", LIB_DIR_ENV);
+    println!("cargo:rerun-if-env-changed={}", ARCHIVE_ENV);
+    println!("cargo:rerun-if-env-changed={}", ARCHIVE_SHA256_ENV);
+
+    // Use the already extracted library from a local directory
+    if let Some(local_lib_dir) = env::var_os(LIB_DIR_ENV) {
+        let local_lib_file = PathBuf::from(&local_lib_dir).join(&lib_filename);
+        if !local_lib_file.is_file() {
+            return Err(format!("{} is set but {} does not exist.", LIB_DIR_ENV, local_lib_file.display()).into());
+        }
+        println!("cargo:rerun-if-changed={}", local_lib_file.display());
+        fs::copy(&local_lib_file, &lib_file)?;
+        let _ = fs::copy(PathBuf::from(&local_lib_dir).join("THIRD-PARTY-NOTICES.txt"), target_dir.join("THIRD-PARTY-NOTICES.txt"));
+        return Ok(());
+    }
+
+    // Extract the library from a pre-downloaded archive
+    if let Some(local_archive) = env::var_os(ARCHIVE_ENV) {
+        let zip_path = PathBuf::from(local_archive);
+        if !zip_path.is_file() {
+            return Err(format!("{} is set but {} does not exist.", ARCHIVE_ENV, zip_path.display()).into());
+        }
+        println!("cargo:rerun-if-changed={}", zip_path.display());
+        verify_archive(&zip_path, version, zip_filename)?;
+        extract_library(&zip_path, lib_path, &target_dir)?;
+        let _ = extract_library(&zip_path, "THIRD-PARTY-NOTICES.txt", &target_dir);
+        return Ok(());
+    }
 
     if !lib_file.exists() {
         println!("cargo:warning=Downloading LexActivator library for target...");
@@ -76,10 +123,21 @@ fn download_and_setup_libs() -> Result<(), Box<dyn std::error::Error>> {
 
         // Download and extract
         let zip_path = temp_dir.join(zip_filename);
-        download_file(&download_url, &zip_path)?;
+        if let Err(e) = download_file(&download_url, &zip_path) {
+            return Err(format!(
+                "{}\n\
+                For offline builds, set one of the following environment variables:\n  \
+                {} - directory containing the extracted {}\n  \
+                {} - path to a pre-downloaded {} (from {})",
+                e,
+                LIB_DIR_ENV, lib_filename,
+                ARCHIVE_ENV, zip_filename, download_url
+            ).into());
+        }
         
-        // Extract the specific library file
-        extract_library(&zip_path, &lib_path, &target_dir)?;
+        // Verify and extract the specific library file
+        verify_archive(&zip_path, version, zip_filename)?;
+        extract_library(&zip_path, lib_path, &target_dir)?;
         let _ = extract_library(&zip_path, "THIRD-PARTY-NOTICES.txt", &target_dir);
         
         // Clean up
@@ -118,14 +176,40 @@ fn get_target_lib_dir(out_dir: &str) -> PathBuf {
     path
 }
 
-fn download_file(url: &str, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+fn download_file(url: &str, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
     let response = ureq::get(url).call()?;
     let mut file = fs::File::create(path)?;
     io::copy(&mut response.into_reader(), &mut file)?;
     Ok(())
 }
 
-fn extract_library(zip_path: &PathBuf, lib_path: &str, target_dir: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+fn verify_archive(zip_path: &Path, version: &str, zip_filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let expected = match env::var(ARCHIVE_SHA256_ENV) {
+        Ok(sha256) => sha256.trim().to_lowercase(),
+        Err(_) => ARCHIVE_SHA256
+            .iter()
+            .find(|(pinned_version, pinned_filename, _)| *pinned_version == version && *pinned_filename == zip_filename)
+            .map(|(_, _, sha256)| sha256.to_string())
+            .ok_or_else(|| format!(
+                "No pinned SHA-256 checksum for {} {}. Add it to ARCHIVE_SHA256 in build.rs or set {} to the expected checksum.",
+                zip_filename, version, ARCHIVE_SHA256_ENV
+            ))?,
+    };
+
+    let mut hasher = Sha256::new();
+    io::copy(&mut fs::File::open(zip_path)?, &mut hasher)?;
+    let actual: String = hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    if actual != expected {
+        return Err(format!(
+            "SHA-256 checksum mismatch for {}: expected {}, found {}. Set {} to override the pinned checksum for custom builds.",
+            zip_path.display(), expected, actual, ARCHIVE_SHA256_ENV
+        ).into());
+    }
+    Ok(())
+}
+
+fn extract_library(zip_path: &Path, lib_path: &str, target_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
     let file = fs::File::open(zip_path)?;
     let mut archive = zip::ZipArchive::new(file)?;
     
@@ -139,13 +223,13 @@ fn extract_library(zip_path: &PathBuf, lib_path: &str, target_dir: &PathBuf) ->
             
             let mut outfile = fs::File::create(&outpath)?;
             io::copy(&mut file, &mut outfile)?;
-            break;
+            return Ok(());
         }
     }
-    Ok(())
+    Err(format!("{} was not found in {}", lib_path, zip_path.display()).into())
 }
 
-fn set_lib_search_dir() -> () {
+fn set_lib_search_dir() {
     let out_dir: String = env::var("OUT_DIR").unwrap();
 
     fn print_link_search_path(base_dir: String, extension_path: &[&str]) {
@@ -235,9 +319,13 @@ fn set_libs_to_link() {
     }
 }
 fn main() {
-    // Download libraries if needed
+    // The stub feature replaces the native library with an in-crate implementation
+    if env::var_os("CARGO_FEATURE_STUB").is_some() {
+        return;
+    }
+    // Download or locate libraries if needed
     if let Err(e) = download_and_setup_libs() {
-        eprintln!("Failed to download libraries: {}", e);
+        eprintln!("Failed to set up LexActivator library: {}", e);
         std::process::exit(1);
     }
     set_lib_search_dir();
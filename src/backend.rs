@@ -0,0 +1,922 @@
+//! Abstraction over the LexActivator API for dependency injection.
+//!
+//! [`LicensingBackend`] mirrors the functions of this crate. [`NativeBackend`] forwards every call to the
+//! LexActivator library, while tests can provide their own implementation and pass it to code that is
+//! generic over the backend, usually through a [`LexActivator`] handle.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! fn check_license<B: LicensingBackend>(lexactivator: &LexActivator<B>) -> bool {
+//!     matches!(lexactivator.is_license_genuine(), Ok(LexActivatorStatus::LA_OK))
+//! }
+//!
+//! struct ExpiredLicense;
+//!
+//! impl LicensingBackend for ExpiredLicense {
+//!     fn is_license_genuine(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+//!         Ok(LexActivatorStatus::LA_EXPIRED)
+//!     }
+//! }
+//!
+//! assert!(check_license(&LexActivator::new()));
+//! assert!(!check_license(&LexActivator::with_backend(ExpiredLicense)));
+//! ```
+
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use crate::*;
+
+/// The LexActivator API as a trait.
+///
+/// Every method behaves like the function of this crate with the same name. The license callback is
+/// process-wide and therefore not part of the trait.
+///
+/// All methods have a default implementation that returns [`LexActivatorError::NotImplemented`] with the name
+/// of the method, so test doubles only need to implement the methods they use and a call to any other method
+/// fails with an error that names it. The timestamp methods and [`LicensingBackend::get_typed_license_type`] are
+/// derived from the raw getters and usually do not need to be implemented.
+#[allow(unused_variables)]
+pub trait LicensingBackend {
+    // --------------- Setter functions ------------------------
+
+    /// See [`crate::set_product_data`].
+    fn set_product_data(&self, product_data: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_product_data"))
+    }
+
+    /// See [`crate::set_product_id`].
+    fn set_product_id(&self, product_id: String, permission_flags: PermissionFlags) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_product_id"))
+    }
+
+    /// See [`crate::set_data_directory`].
+    fn set_data_directory(&self, data_dir: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_data_directory"))
+    }
+
+    /// See [`crate::set_debug_mode`].
+    fn set_debug_mode(&self, enable: u32) {}
+
+    /// See [`crate::set_cache_mode`].
+    fn set_cache_mode(&self, mode: bool) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_cache_mode"))
+    }
+
+    /// See [`crate::set_custom_device_fingerprint`].
+    fn set_custom_device_fingerprint(&self, device_fingerprint: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_custom_device_fingerprint"))
+    }
+
+    /// See [`crate::set_license_key`].
+    fn set_license_key(&self, license_key: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_license_key"))
+    }
+
+    /// See [`crate::set_license_user_credential`].
+    fn set_license_user_credential(&self, email: String, password: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_license_user_credential"))
+    }
+
+    /// See [`crate::set_activation_lease_duration`].
+    fn set_activation_lease_duration(&self, lease_duration: i64) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_activation_lease_duration"))
+    }
+
+    /// See [`crate::set_activation_metadata`].
+    fn set_activation_metadata(&self, key: String, value: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_activation_metadata"))
+    }
+
+    /// See [`crate::set_trial_activation_metadata`].
+    fn set_trial_activation_metadata(&self, key: String, value: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_trial_activation_metadata"))
+    }
+
+    /// See [`crate::set_release_version`].
+    fn set_release_version(&self, version: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_release_version"))
+    }
+
+    /// See [`crate::set_release_published_date`].
+    fn set_release_published_date(&self, release_published_date: u32) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_release_published_date"))
+    }
+
+    /// See [`crate::set_release_platform`].
+    fn set_release_platform(&self, platform: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_release_platform"))
+    }
+
+    /// See [`crate::set_release_channel`].
+    fn set_release_channel(&self, channel: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_release_channel"))
+    }
+
+    /// See [`crate::set_offline_activation_request_meter_attribute_uses`].
+    fn set_offline_activation_request_meter_attribute_uses(&self, name: String, uses: i32) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_offline_activation_request_meter_attribute_uses"))
+    }
+
+    /// See [`crate::set_network_proxy`].
+    fn set_network_proxy(&self, proxy: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_network_proxy"))
+    }
+
+    /// See [`crate::set_cryptlex_host`].
+    fn set_cryptlex_host(&self, host: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_cryptlex_host"))
+    }
+
+    /// See [`crate::set_two_factor_authentication_code`].
+    fn set_two_factor_authentication_code(&self, two_factor_authentication_code: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("set_two_factor_authentication_code"))
+    }
+
+
+    // ------------------- Getter Functions --------------------
+
+    /// See [`crate::get_product_metadata`].
+    fn get_product_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_product_metadata"))
+    }
+
+    /// See [`crate::get_product_version_name`].
+    fn get_product_version_name(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_product_version_name"))
+    }
+
+    /// See [`crate::get_product_version_display_name`].
+    fn get_product_version_display_name(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_product_version_display_name"))
+    }
+
+    /// See [`crate::get_product_version_feature_flag`].
+    fn get_product_version_feature_flag(&self, name: String) -> Result<ProductVersionFeatureFlag, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_product_version_feature_flag"))
+    }
+
+    /// See [`crate::get_license_metadata`].
+    fn get_license_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_metadata"))
+    }
+
+    /// See [`crate::get_license_meterattribute`].
+    fn get_license_meterattribute(&self, name: String) -> Result<LicenseMeterAttribute, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_meterattribute"))
+    }
+
+    /// See [`crate::get_license_key`].
+    fn get_license_key(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_key"))
+    }
+
+    /// See [`crate::get_license_allowed_activations`].
+    fn get_license_allowed_activations(&self) -> Result<i64, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_allowed_activations"))
+    }
+
+    /// See [`crate::get_license_total_activations`].
+    fn get_license_total_activations(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_total_activations"))
+    }
+
+    /// See [`crate::get_license_allowed_deactivations`].
+    fn get_license_allowed_deactivations(&self) -> Result<i64, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_allowed_deactivations"))
+    }
+
+    /// See [`crate::get_license_total_deactivations`].
+    fn get_license_total_deactivations(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_total_deactivations"))
+    }
+
+    /// See [`crate::get_license_creation_date`].
+    fn get_license_creation_date(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_creation_date"))
+    }
+
+    /// See [`crate::get_license_activation_date`].
+    fn get_license_activation_date(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_activation_date"))
+    }
+
+    /// See [`crate::get_activation_last_synced_date`].
+    fn get_activation_last_synced_date(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_activation_last_synced_date"))
+    }
+
+    /// See [`crate::get_license_expiry_date`].
+    fn get_license_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_expiry_date"))
+    }
+
+    /// See [`crate::get_license_maintenance_expiry_date`].
+    fn get_license_maintenance_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_maintenance_expiry_date"))
+    }
+
+    /// See [`crate::get_license_max_allowed_release_version`].
+    fn get_license_max_allowed_release_version(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_max_allowed_release_version"))
+    }
+
+    /// See [`crate::get_license_user_email`].
+    fn get_license_user_email(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_user_email"))
+    }
+
+    /// See [`crate::get_license_user_name`].
+    fn get_license_user_name(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_user_name"))
+    }
+
+    /// See [`crate::get_license_user_company`].
+    fn get_license_user_company(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_user_company"))
+    }
+
+    /// See [`crate::get_license_user_metadata`].
+    fn get_license_user_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_user_metadata"))
+    }
+
+    /// See [`crate::get_license_organization_name`].
+    fn get_license_organization_name(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_organization_name"))
+    }
+
+    /// See [`crate::get_license_organization_address`].
+    fn get_license_organization_address(&self) -> Result<OrganizationAddress, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_organization_address"))
+    }
+
+    /// See [`crate::get_user_licenses`].
+    fn get_user_licenses(&self) -> Result<Vec<UserLicense>, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_user_licenses"))
+    }
+
+    /// See [`crate::get_license_entitlement_set_name`].
+    fn get_license_entitlement_set_name(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_entitlement_set_name"))
+    }
+
+    /// See [`crate::get_license_entitlement_set_display_name`].
+    fn get_license_entitlement_set_display_name(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_entitlement_set_display_name"))
+    }
+
+    /// See [`crate::get_license_entitlement_set_tier`].
+    fn get_license_entitlement_set_tier(&self) -> Result<i64, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_entitlement_set_tier"))
+    }
+
+    /// See [`crate::get_feature_entitlements`].
+    fn get_feature_entitlements(&self) -> Result<Vec<FeatureEntitlement>, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_feature_entitlements"))
+    }
+
+    /// See [`crate::get_feature_entitlement`].
+    fn get_feature_entitlement(&self, feature_name: String) -> Result<FeatureEntitlement, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_feature_entitlement"))
+    }
+
+    /// See [`crate::get_license_type`].
+    fn get_license_type(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_license_type"))
+    }
+
+    /// See [`crate::get_activation_id`].
+    fn get_activation_id(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_activation_id"))
+    }
+
+    /// See [`crate::get_activation_metadata`].
+    fn get_activation_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_activation_metadata"))
+    }
+
+    /// See [`crate::get_activation_mode`].
+    fn get_activation_mode(&self) -> Result<ActivationMode, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_activation_mode"))
+    }
+
+    /// See [`crate::get_activation_meter_attribute_uses`].
+    fn get_activation_meter_attribute_uses(&self, name: String) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_activation_meter_attribute_uses"))
+    }
+
+    /// See [`crate::get_server_sync_grace_period_expiry_date`].
+    fn get_server_sync_grace_period_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_server_sync_grace_period_expiry_date"))
+    }
+
+    /// See [`crate::get_last_activation_error`].
+    fn get_last_activation_error(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_last_activation_error"))
+    }
+
+    /// See [`crate::get_trial_activation_metadata`].
+    fn get_trial_activation_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_trial_activation_metadata"))
+    }
+
+    /// See [`crate::get_trial_expiry_date`].
+    fn get_trial_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_trial_expiry_date"))
+    }
+
+    /// See [`crate::get_trial_id`].
+    fn get_trial_id(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_trial_id"))
+    }
+
+    /// See [`crate::get_local_trial_expiry_date`].
+    fn get_local_trial_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_local_trial_expiry_date"))
+    }
+
+    /// See [`crate::get_library_version`].
+    fn get_library_version(&self) -> Result<String, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("get_library_version"))
+    }
+
+
+    // ------------------ Action Functions ------------------
+
+    /// See [`crate::authenticate_user`].
+    fn authenticate_user(&self, email: String, password: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("authenticate_user"))
+    }
+
+    /// See [`crate::authenticate_user_with_id_token`].
+    fn authenticate_user_with_id_token(&self, id_token: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("authenticate_user_with_id_token"))
+    }
+
+    /// See [`crate::activate_license`].
+    fn activate_license(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("activate_license"))
+    }
+
+    /// See [`crate::activate_license_offline`].
+    fn activate_license_offline(&self, file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("activate_license_offline"))
+    }
+
+    /// See [`crate::generate_offline_activation_request`].
+    fn generate_offline_activation_request(&self, file_path: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("generate_offline_activation_request"))
+    }
+
+    /// See [`crate::deactivate_license`].
+    fn deactivate_license(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("deactivate_license"))
+    }
+
+    /// See [`crate::generate_offline_deactivation_request`].
+    fn generate_offline_deactivation_request(&self, file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("generate_offline_deactivation_request"))
+    }
+
+    /// See [`crate::is_license_genuine`].
+    fn is_license_genuine(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("is_license_genuine"))
+    }
+
+    /// See [`crate::is_license_valid`].
+    fn is_license_valid(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("is_license_valid"))
+    }
+
+    /// See [`crate::sync_license_activation`].
+    fn sync_license_activation(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("sync_license_activation"))
+    }
+
+    /// See [`crate::activate_trial`].
+    fn activate_trial(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("activate_trial"))
+    }
+
+    /// See [`crate::sync_trial_activation`].
+    fn sync_trial_activation(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("sync_trial_activation"))
+    }
+
+    /// See [`crate::activate_trial_offline`].
+    fn activate_trial_offline(&self, file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("activate_trial_offline"))
+    }
+
+    /// See [`crate::generate_offline_trial_activation_request`].
+    fn generate_offline_trial_activation_request(&self, file_path: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("generate_offline_trial_activation_request"))
+    }
+
+    /// See [`crate::is_trial_genuine`].
+    fn is_trial_genuine(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("is_trial_genuine"))
+    }
+
+    /// See [`crate::activate_local_trial`].
+    fn activate_local_trial(&self, trial_length: u32) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("activate_local_trial"))
+    }
+
+    /// See [`crate::is_local_trial_genuine`].
+    fn is_local_trial_genuine(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("is_local_trial_genuine"))
+    }
+
+    /// See [`crate::extend_local_trial`].
+    fn extend_local_trial(&self, trial_extension_length: u32) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("extend_local_trial"))
+    }
+
+    /// See [`crate::increment_activation_meter_attribute_uses`].
+    fn increment_activation_meter_attribute_uses(&self, name: String, increment: u32) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("increment_activation_meter_attribute_uses"))
+    }
+
+    /// See [`crate::decrement_activation_meter_attribute_uses`].
+    fn decrement_activation_meter_attribute_uses(&self, name: String, decrement: u32) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("decrement_activation_meter_attribute_uses"))
+    }
+
+    /// See [`crate::reset_activation_meter_attribute_uses`].
+    fn reset_activation_meter_attribute_uses(&self, name: String) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("reset_activation_meter_attribute_uses"))
+    }
+
+    /// See [`crate::migrate_to_system_wide_activation`].
+    fn migrate_to_system_wide_activation(&self, old_permission_flag: PermissionFlags) -> Result<LexActivatorStatus, LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("migrate_to_system_wide_activation"))
+    }
+
+    /// See [`crate::reset`].
+    fn reset(&self) -> Result<(), LexActivatorError> {
+        Err(LexActivatorError::NotImplemented("reset"))
+    }
+
+    // ------------------ Derived Functions ------------------
+
+    /// See [`crate::get_typed_license_type`]. Derived from [`LicensingBackend::get_license_type`].
+    fn get_typed_license_type(&self) -> Result<LicenseType, LexActivatorError> {
+        self.get_license_type().map(LicenseType::from)
+    }
+
+    /// See [`crate::get_license_creation_time`]. Derived from [`LicensingBackend::get_license_creation_date`].
+    fn get_license_creation_time(&self) -> Result<Option<SystemTime>, LexActivatorError> {
+        self.get_license_creation_date().map(time_utils::timestamp_to_system_time)
+    }
+
+    /// See [`crate::get_license_activation_time`]. Derived from [`LicensingBackend::get_license_activation_date`].
+    fn get_license_activation_time(&self) -> Result<Option<SystemTime>, LexActivatorError> {
+        self.get_license_activation_date().map(time_utils::timestamp_to_system_time)
+    }
+
+    /// See [`crate::get_activation_last_synced_time`]. Derived from [`LicensingBackend::get_activation_last_synced_date`].
+    fn get_activation_last_synced_time(&self) -> Result<Option<SystemTime>, LexActivatorError> {
+        self.get_activation_last_synced_date().map(time_utils::timestamp_to_system_time)
+    }
+
+    /// See [`crate::get_license_expiry_time`]. Derived from [`LicensingBackend::get_license_expiry_date`].
+    fn get_license_expiry_time(&self) -> Result<Option<SystemTime>, LexActivatorError> {
+        self.get_license_expiry_date().map(time_utils::timestamp_to_system_time)
+    }
+
+    /// See [`crate::get_license_maintenance_expiry_time`]. Derived from [`LicensingBackend::get_license_maintenance_expiry_date`].
+    fn get_license_maintenance_expiry_time(&self) -> Result<Option<SystemTime>, LexActivatorError> {
+        self.get_license_maintenance_expiry_date().map(time_utils::timestamp_to_system_time)
+    }
+
+    /// See [`crate::get_server_sync_grace_period_expiry_time`]. Derived from [`LicensingBackend::get_server_sync_grace_period_expiry_date`].
+    fn get_server_sync_grace_period_expiry_time(&self) -> Result<Option<SystemTime>, LexActivatorError> {
+        self.get_server_sync_grace_period_expiry_date().map(time_utils::timestamp_to_system_time)
+    }
+
+    /// See [`crate::get_trial_expiry_time`]. Derived from [`LicensingBackend::get_trial_expiry_date`].
+    fn get_trial_expiry_time(&self) -> Result<Option<SystemTime>, LexActivatorError> {
+        self.get_trial_expiry_date().map(time_utils::timestamp_to_system_time)
+    }
+
+    /// See [`crate::get_local_trial_expiry_time`]. Derived from [`LicensingBackend::get_local_trial_expiry_date`].
+    fn get_local_trial_expiry_time(&self) -> Result<Option<SystemTime>, LexActivatorError> {
+        self.get_local_trial_expiry_date().map(time_utils::timestamp_to_system_time)
+    }
+
+    /// See [`crate::get_license_time_remaining`]. Derived from [`LicensingBackend::get_license_expiry_time`].
+    fn get_license_time_remaining(&self) -> Result<Option<Duration>, LexActivatorError> {
+        self.get_license_expiry_time().map(time_utils::time_remaining)
+    }
+
+    /// See [`crate::get_license_maintenance_time_remaining`]. Derived from [`LicensingBackend::get_license_maintenance_expiry_time`].
+    fn get_license_maintenance_time_remaining(&self) -> Result<Option<Duration>, LexActivatorError> {
+        self.get_license_maintenance_expiry_time().map(time_utils::time_remaining)
+    }
+
+    /// See [`crate::get_server_sync_grace_period_time_remaining`]. Derived from [`LicensingBackend::get_server_sync_grace_period_expiry_time`].
+    fn get_server_sync_grace_period_time_remaining(&self) -> Result<Option<Duration>, LexActivatorError> {
+        self.get_server_sync_grace_period_expiry_time().map(time_utils::time_remaining)
+    }
+
+    /// See [`crate::get_trial_time_remaining`]. Derived from [`LicensingBackend::get_trial_expiry_time`].
+    fn get_trial_time_remaining(&self) -> Result<Option<Duration>, LexActivatorError> {
+        self.get_trial_expiry_time().map(time_utils::time_remaining)
+    }
+
+    /// See [`crate::get_local_trial_time_remaining`]. Derived from [`LicensingBackend::get_local_trial_expiry_time`].
+    fn get_local_trial_time_remaining(&self) -> Result<Option<Duration>, LexActivatorError> {
+        self.get_local_trial_expiry_time().map(time_utils::time_remaining)
+    }
+}
+
+/// A [`LicensingBackend`] that calls the LexActivator library.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NativeBackend;
+
+impl LicensingBackend for NativeBackend {
+    fn set_product_data(&self, product_data: String) -> Result<(), LexActivatorError> {
+        crate::set_product_data(product_data)
+    }
+
+    fn set_product_id(&self, product_id: String, permission_flags: PermissionFlags) -> Result<(), LexActivatorError> {
+        crate::set_product_id(product_id, permission_flags)
+    }
+
+    fn set_data_directory(&self, data_dir: String) -> Result<(), LexActivatorError> {
+        crate::set_data_directory(data_dir)
+    }
+
+    fn set_debug_mode(&self, enable: u32) {
+        crate::set_debug_mode(enable)
+    }
+
+    fn set_cache_mode(&self, mode: bool) -> Result<(), LexActivatorError> {
+        crate::set_cache_mode(mode)
+    }
+
+    fn set_custom_device_fingerprint(&self, device_fingerprint: String) -> Result<(), LexActivatorError> {
+        crate::set_custom_device_fingerprint(device_fingerprint)
+    }
+
+    fn set_license_key(&self, license_key: String) -> Result<(), LexActivatorError> {
+        crate::set_license_key(license_key)
+    }
+
+    fn set_license_user_credential(&self, email: String, password: String) -> Result<(), LexActivatorError> {
+        crate::set_license_user_credential(email, password)
+    }
+
+    fn set_activation_lease_duration(&self, lease_duration: i64) -> Result<(), LexActivatorError> {
+        crate::set_activation_lease_duration(lease_duration)
+    }
+
+    fn set_activation_metadata(&self, key: String, value: String) -> Result<(), LexActivatorError> {
+        crate::set_activation_metadata(key, value)
+    }
+
+    fn set_trial_activation_metadata(&self, key: String, value: String) -> Result<(), LexActivatorError> {
+        crate::set_trial_activation_metadata(key, value)
+    }
+
+    fn set_release_version(&self, version: String) -> Result<(), LexActivatorError> {
+        crate::set_release_version(version)
+    }
+
+    fn set_release_published_date(&self, release_published_date: u32) -> Result<(), LexActivatorError> {
+        crate::set_release_published_date(release_published_date)
+    }
+
+    fn set_release_platform(&self, platform: String) -> Result<(), LexActivatorError> {
+        crate::set_release_platform(platform)
+    }
+
+    fn set_release_channel(&self, channel: String) -> Result<(), LexActivatorError> {
+        crate::set_release_channel(channel)
+    }
+
+    fn set_offline_activation_request_meter_attribute_uses(&self, name: String, uses: i32) -> Result<(), LexActivatorError> {
+        crate::set_offline_activation_request_meter_attribute_uses(name, uses)
+    }
+
+    fn set_network_proxy(&self, proxy: String) -> Result<(), LexActivatorError> {
+        crate::set_network_proxy(proxy)
+    }
+
+    fn set_cryptlex_host(&self, host: String) -> Result<(), LexActivatorError> {
+        crate::set_cryptlex_host(host)
+    }
+
+    fn set_two_factor_authentication_code(&self, two_factor_authentication_code: String) -> Result<(), LexActivatorError> {
+        crate::set_two_factor_authentication_code(two_factor_authentication_code)
+    }
+
+    fn get_product_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        crate::get_product_metadata(key)
+    }
+
+    fn get_product_version_name(&self) -> Result<String, LexActivatorError> {
+        crate::get_product_version_name()
+    }
+
+    fn get_product_version_display_name(&self) -> Result<String, LexActivatorError> {
+        crate::get_product_version_display_name()
+    }
+
+    fn get_product_version_feature_flag(&self, name: String) -> Result<ProductVersionFeatureFlag, LexActivatorError> {
+        crate::get_product_version_feature_flag(name)
+    }
+
+    fn get_license_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        crate::get_license_metadata(key)
+    }
+
+    fn get_license_meterattribute(&self, name: String) -> Result<LicenseMeterAttribute, LexActivatorError> {
+        crate::get_license_meterattribute(name)
+    }
+
+    fn get_license_key(&self) -> Result<String, LexActivatorError> {
+        crate::get_license_key()
+    }
+
+    fn get_license_allowed_activations(&self) -> Result<i64, LexActivatorError> {
+        crate::get_license_allowed_activations()
+    }
+
+    fn get_license_total_activations(&self) -> Result<u32, LexActivatorError> {
+        crate::get_license_total_activations()
+    }
+
+    fn get_license_allowed_deactivations(&self) -> Result<i64, LexActivatorError> {
+        crate::get_license_allowed_deactivations()
+    }
+
+    fn get_license_total_deactivations(&self) -> Result<u32, LexActivatorError> {
+        crate::get_license_total_deactivations()
+    }
+
+    fn get_license_creation_date(&self) -> Result<u32, LexActivatorError> {
+        crate::get_license_creation_date()
+    }
+
+    fn get_license_activation_date(&self) -> Result<u32, LexActivatorError> {
+        crate::get_license_activation_date()
+    }
+
+    fn get_activation_last_synced_date(&self) -> Result<u32, LexActivatorError> {
+        crate::get_activation_last_synced_date()
+    }
+
+    fn get_license_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        crate::get_license_expiry_date()
+    }
+
+    fn get_license_maintenance_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        crate::get_license_maintenance_expiry_date()
+    }
+
+    fn get_license_max_allowed_release_version(&self) -> Result<String, LexActivatorError> {
+        crate::get_license_max_allowed_release_version()
+    }
+
+    fn get_license_user_email(&self) -> Result<String, LexActivatorError> {
+        crate::get_license_user_email()
+    }
+
+    fn get_license_user_name(&self) -> Result<String, LexActivatorError> {
+        crate::get_license_user_name()
+    }
+
+    fn get_license_user_company(&self) -> Result<String, LexActivatorError> {
+        crate::get_license_user_company()
+    }
+
+    fn get_license_user_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        crate::get_license_user_metadata(key)
+    }
+
+    fn get_license_organization_name(&self) -> Result<String, LexActivatorError> {
+        crate::get_license_organization_name()
+    }
+
+    fn get_license_organization_address(&self) -> Result<OrganizationAddress, LexActivatorError> {
+        crate::get_license_organization_address()
+    }
+
+    fn get_user_licenses(&self) -> Result<Vec<UserLicense>, LexActivatorError> {
+        crate::get_user_licenses()
+    }
+
+    fn get_license_entitlement_set_name(&self) -> Result<String, LexActivatorError> {
+        crate::get_license_entitlement_set_name()
+    }
+
+    fn get_license_entitlement_set_display_name(&self) -> Result<String, LexActivatorError> {
+        crate::get_license_entitlement_set_display_name()
+    }
+
+    fn get_license_entitlement_set_tier(&self) -> Result<i64, LexActivatorError> {
+        crate::get_license_entitlement_set_tier()
+    }
+
+    fn get_feature_entitlements(&self) -> Result<Vec<FeatureEntitlement>, LexActivatorError> {
+        crate::get_feature_entitlements()
+    }
+
+    fn get_feature_entitlement(&self, feature_name: String) -> Result<FeatureEntitlement, LexActivatorError> {
+        crate::get_feature_entitlement(feature_name)
+    }
+
+    fn get_license_type(&self) -> Result<String, LexActivatorError> {
+        crate::get_license_type()
+    }
+
+    fn get_activation_id(&self) -> Result<String, LexActivatorError> {
+        crate::get_activation_id()
+    }
+
+    fn get_activation_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        crate::get_activation_metadata(key)
+    }
+
+    fn get_activation_mode(&self) -> Result<ActivationMode, LexActivatorError> {
+        crate::get_activation_mode()
+    }
+
+    fn get_activation_meter_attribute_uses(&self, name: String) -> Result<u32, LexActivatorError> {
+        crate::get_activation_meter_attribute_uses(name)
+    }
+
+    fn get_server_sync_grace_period_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        crate::get_server_sync_grace_period_expiry_date()
+    }
+
+    fn get_last_activation_error(&self) -> Result<u32, LexActivatorError> {
+        crate::get_last_activation_error()
+    }
+
+    fn get_trial_activation_metadata(&self, key: String) -> Result<String, LexActivatorError> {
+        crate::get_trial_activation_metadata(key)
+    }
+
+    fn get_trial_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        crate::get_trial_expiry_date()
+    }
+
+    fn get_trial_id(&self) -> Result<String, LexActivatorError> {
+        crate::get_trial_id()
+    }
+
+    fn get_local_trial_expiry_date(&self) -> Result<u32, LexActivatorError> {
+        crate::get_local_trial_expiry_date()
+    }
+
+    fn get_library_version(&self) -> Result<String, LexActivatorError> {
+        crate::get_library_version()
+    }
+
+    fn authenticate_user(&self, email: String, password: String) -> Result<(), LexActivatorError> {
+        crate::authenticate_user(email, password)
+    }
+
+    fn authenticate_user_with_id_token(&self, id_token: String) -> Result<(), LexActivatorError> {
+        crate::authenticate_user_with_id_token(id_token)
+    }
+
+    fn activate_license(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::activate_license()
+    }
+
+    fn activate_license_offline(&self, file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::activate_license_offline(file_path)
+    }
+
+    fn generate_offline_activation_request(&self, file_path: String) -> Result<(), LexActivatorError> {
+        crate::generate_offline_activation_request(file_path)
+    }
+
+    fn deactivate_license(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::deactivate_license()
+    }
+
+    fn generate_offline_deactivation_request(&self, file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::generate_offline_deactivation_request(file_path)
+    }
+
+    fn is_license_genuine(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::is_license_genuine()
+    }
+
+    fn is_license_valid(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::is_license_valid()
+    }
+
+    fn sync_license_activation(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::sync_license_activation()
+    }
+
+    fn activate_trial(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::activate_trial()
+    }
+
+    fn sync_trial_activation(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::sync_trial_activation()
+    }
+
+    fn activate_trial_offline(&self, file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::activate_trial_offline(file_path)
+    }
+
+    fn generate_offline_trial_activation_request(&self, file_path: String) -> Result<(), LexActivatorError> {
+        crate::generate_offline_trial_activation_request(file_path)
+    }
+
+    fn is_trial_genuine(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::is_trial_genuine()
+    }
+
+    fn activate_local_trial(&self, trial_length: u32) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::activate_local_trial(trial_length)
+    }
+
+    fn is_local_trial_genuine(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::is_local_trial_genuine()
+    }
+
+    fn extend_local_trial(&self, trial_extension_length: u32) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::extend_local_trial(trial_extension_length)
+    }
+
+    fn increment_activation_meter_attribute_uses(&self, name: String, increment: u32) -> Result<(), LexActivatorError> {
+        crate::increment_activation_meter_attribute_uses(name, increment)
+    }
+
+    fn decrement_activation_meter_attribute_uses(&self, name: String, decrement: u32) -> Result<(), LexActivatorError> {
+        crate::decrement_activation_meter_attribute_uses(name, decrement)
+    }
+
+    fn reset_activation_meter_attribute_uses(&self, name: String) -> Result<(), LexActivatorError> {
+        crate::reset_activation_meter_attribute_uses(name)
+    }
+
+    fn migrate_to_system_wide_activation(&self, old_permission_flag: PermissionFlags) -> Result<LexActivatorStatus, LexActivatorError> {
+        crate::migrate_to_system_wide_activation(old_permission_flag)
+    }
+
+    fn reset(&self) -> Result<(), LexActivatorError> {
+        crate::reset()
+    }
+}
+
+/// A cheaply cloneable handle to a [`LicensingBackend`].
+///
+/// The methods of the backend can be called on the handle directly. Code that takes a `LexActivator<B>`
+/// with a generic `B: LicensingBackend` can be used with the LexActivator library in production and with
+/// a fake backend in tests.
+#[derive(Debug)]
+pub struct LexActivator<B: LicensingBackend = NativeBackend> {
+    backend: Arc<B>,
+}
+
+impl LexActivator<NativeBackend> {
+    /// Creates a handle that calls the LexActivator library.
+    pub fn new() -> Self {
+        LexActivator::with_backend(NativeBackend)
+    }
+}
+
+impl Default for LexActivator<NativeBackend> {
+    fn default() -> Self {
+        LexActivator::new()
+    }
+}
+
+impl<B: LicensingBackend> LexActivator<B> {
+    /// Creates a handle that calls the given backend.
+    pub fn with_backend(backend: B) -> Self {
+        LexActivator { backend: Arc::new(backend) }
+    }
+
+    /// Returns the backend of the handle.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+}
+
+impl<B: LicensingBackend> Clone for LexActivator<B> {
+    fn clone(&self) -> Self {
+        LexActivator { backend: Arc::clone(&self.backend) }
+    }
+}
+
+impl<B: LicensingBackend> Deref for LexActivator<B> {
+    type Target = B;
+
+    fn deref(&self) -> &B {
+        &self.backend
+    }
+}
@@ -0,0 +1,408 @@
+//! One-shot initialisation of the LexActivator library.
+//!
+//! The setter functions of the LexActivator library have to be called in a specific order, e.g. the product
+//! data and ID before anything else. [`LexActivatorConfig`] collects the settings, validates them and applies
+//! them in the correct order. The [`ConfigStep`] that failed is part of the returned [`ConfigError`].
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! let lexactivator = LexActivatorConfig::new("PRODUCT_DATA", "PRODUCT_ID")
+//!     .permission_flags(PermissionFlags::LA_USER)
+//!     .release_version("1.2.0")
+//!     .license_key("LICENSE_KEY")
+//!     .build()?;
+//!
+//! lexactivator.activate_license()?;
+//! ```
+
+use std::fmt;
+use std::path::Path;
+
+use crate::*;
+
+/// Maximum length of activation and trial activation metadata keys.
+const METADATA_KEY_MAX_LENGTH: usize = 256;
+
+/// Maximum length of activation and trial activation metadata values.
+const METADATA_VALUE_MAX_LENGTH: usize = 4096;
+
+/// A step of the initialisation, named after the setter function it calls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigStep {
+    /// [`set_debug_mode`].
+    DebugMode,
+    /// [`set_product_data`].
+    ProductData,
+    /// [`set_product_id`].
+    ProductId,
+    /// [`set_data_directory`].
+    DataDirectory,
+    /// [`set_cache_mode`].
+    CacheMode,
+    /// [`set_custom_device_fingerprint`].
+    CustomDeviceFingerprint,
+    /// [`set_network_proxy`].
+    NetworkProxy,
+    /// [`set_cryptlex_host`].
+    CryptlexHost,
+    /// [`set_release_version`].
+    ReleaseVersion,
+    /// [`set_release_published_date`].
+    ReleasePublishedDate,
+    /// [`set_release_platform`].
+    ReleasePlatform,
+    /// [`set_release_channel`].
+    ReleaseChannel,
+    /// [`set_activation_lease_duration`].
+    ActivationLeaseDuration,
+    /// Setting the activation metadata with the given key.
+    ActivationMetadata(String),
+    /// Setting the trial activation metadata with the given key.
+    TrialActivationMetadata(String),
+    /// [`set_license_key`].
+    LicenseKey,
+}
+
+impl fmt::Display for ConfigStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigStep::DebugMode => write!(f, "set_debug_mode"),
+            ConfigStep::ProductData => write!(f, "set_product_data"),
+            ConfigStep::ProductId => write!(f, "set_product_id"),
+            ConfigStep::DataDirectory => write!(f, "set_data_directory"),
+            ConfigStep::CacheMode => write!(f, "set_cache_mode"),
+            ConfigStep::CustomDeviceFingerprint => write!(f, "set_custom_device_fingerprint"),
+            ConfigStep::NetworkProxy => write!(f, "set_network_proxy"),
+            ConfigStep::CryptlexHost => write!(f, "set_cryptlex_host"),
+            ConfigStep::ReleaseVersion => write!(f, "set_release_version"),
+            ConfigStep::ReleasePublishedDate => write!(f, "set_release_published_date"),
+            ConfigStep::ReleasePlatform => write!(f, "set_release_platform"),
+            ConfigStep::ReleaseChannel => write!(f, "set_release_channel"),
+            ConfigStep::ActivationLeaseDuration => write!(f, "set_activation_lease_duration"),
+            ConfigStep::ActivationMetadata(key) => write!(f, "set_activation_metadata({:?})", key),
+            ConfigStep::TrialActivationMetadata(key) => write!(f, "set_trial_activation_metadata({:?})", key),
+            ConfigStep::LicenseKey => write!(f, "set_license_key"),
+        }
+    }
+}
+
+/// Error returned by [`LexActivatorConfig::validate`] and [`LexActivatorConfig::build`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    /// A value was rejected before anything was passed to the LexActivator library.
+    InvalidValue {
+        step: ConfigStep,
+        reason: &'static str,
+    },
+    /// The LexActivator library returned an error. The steps before `step` have been applied.
+    StepFailed {
+        step: ConfigStep,
+        error: LexActivatorError,
+    },
+}
+
+impl ConfigError {
+    /// Returns the step that failed.
+    pub fn step(&self) -> &ConfigStep {
+        match self {
+            ConfigError::InvalidValue { step, .. } | ConfigError::StepFailed { step, .. } => step,
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidValue { step, reason } => write!(f, "Invalid value for {}: {}", step, reason),
+            ConfigError::StepFailed { step, error } => write!(f, "{} failed: {}", step, error),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::InvalidValue { .. } => None,
+            ConfigError::StepFailed { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Builder for the initialisation of the LexActivator library.
+///
+/// Only the product data and product ID are required. Every other setting is applied only if it was set.
+#[derive(Debug, Clone)]
+pub struct LexActivatorConfig {
+    product_data: String,
+    product_id: String,
+    permission_flags: PermissionFlags,
+    debug_mode: Option<bool>,
+    data_directory: Option<String>,
+    cache_mode: Option<bool>,
+    custom_device_fingerprint: Option<String>,
+    network_proxy: Option<String>,
+    cryptlex_host: Option<String>,
+    release_version: Option<String>,
+    release_published_date: Option<u32>,
+    release_platform: Option<String>,
+    release_channel: Option<String>,
+    activation_lease_duration: Option<i64>,
+    activation_metadata: Vec<(String, String)>,
+    trial_activation_metadata: Vec<(String, String)>,
+    license_key: Option<String>,
+}
+
+impl LexActivatorConfig {
+    /// Creates a configuration with the content of the Product.dat file and the product ID.
+    /// The permission flags default to `LA_USER`.
+    pub fn new(product_data: impl Into<String>, product_id: impl Into<String>) -> Self {
+        LexActivatorConfig {
+            product_data: product_data.into(),
+            product_id: product_id.into(),
+            permission_flags: PermissionFlags::LA_USER,
+            debug_mode: None,
+            data_directory: None,
+            cache_mode: None,
+            custom_device_fingerprint: None,
+            network_proxy: None,
+            cryptlex_host: None,
+            release_version: None,
+            release_published_date: None,
+            release_platform: None,
+            release_channel: None,
+            activation_lease_duration: None,
+            activation_metadata: Vec::new(),
+            trial_activation_metadata: Vec::new(),
+            license_key: None,
+        }
+    }
+
+    /// Sets the permission flags passed to [`set_product_id`].
+    pub fn permission_flags(mut self, permission_flags: PermissionFlags) -> Self {
+        self.permission_flags = permission_flags;
+        self
+    }
+
+    /// See [`set_debug_mode`].
+    pub fn debug_mode(mut self, enable: bool) -> Self {
+        self.debug_mode = Some(enable);
+        self
+    }
+
+    /// See [`set_data_directory`]. The path must be absolute.
+    pub fn data_directory(mut self, data_dir: impl Into<String>) -> Self {
+        self.data_directory = Some(data_dir.into());
+        self
+    }
+
+    /// See [`set_cache_mode`].
+    pub fn cache_mode(mut self, mode: bool) -> Self {
+        self.cache_mode = Some(mode);
+        self
+    }
+
+    /// See [`set_custom_device_fingerprint`].
+    pub fn custom_device_fingerprint(mut self, device_fingerprint: impl Into<String>) -> Self {
+        self.custom_device_fingerprint = Some(device_fingerprint.into());
+        self
+    }
+
+    /// See [`set_network_proxy`].
+    pub fn network_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.network_proxy = Some(proxy.into());
+        self
+    }
+
+    /// See [`set_cryptlex_host`].
+    pub fn cryptlex_host(mut self, host: impl Into<String>) -> Self {
+        self.cryptlex_host = Some(host.into());
+        self
+    }
+
+    /// See [`set_release_version`].
+    pub fn release_version(mut self, version: impl Into<String>) -> Self {
+        self.release_version = Some(version.into());
+        self
+    }
+
+    /// See [`set_release_published_date`].
+    pub fn release_published_date(mut self, release_published_date: u32) -> Self {
+        self.release_published_date = Some(release_published_date);
+        self
+    }
+
+    /// See [`set_release_platform`].
+    pub fn release_platform(mut self, platform: impl Into<String>) -> Self {
+        self.release_platform = Some(platform.into());
+        self
+    }
+
+    /// See [`set_release_channel`].
+    pub fn release_channel(mut self, channel: impl Into<String>) -> Self {
+        self.release_channel = Some(channel.into());
+        self
+    }
+
+    /// See [`set_activation_lease_duration`].
+    pub fn activation_lease_duration(mut self, lease_duration: i64) -> Self {
+        self.activation_lease_duration = Some(lease_duration);
+        self
+    }
+
+    /// See [`set_activation_metadata`]. Can be called once per key; [`LexActivatorConfig::validate`] rejects
+    /// duplicate keys.
+    pub fn activation_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.activation_metadata.push((key.into(), value.into()));
+        self
+    }
+
+    /// See [`set_trial_activation_metadata`]. Can be called once per key, like
+    /// [`LexActivatorConfig::activation_metadata`].
+    pub fn trial_activation_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.trial_activation_metadata.push((key.into(), value.into()));
+        self
+    }
+
+    /// See [`set_license_key`].
+    pub fn license_key(mut self, license_key: impl Into<String>) -> Self {
+        self.license_key = Some(license_key.into());
+        self
+    }
+
+    /// Checks the values without calling the LexActivator library.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if all values are valid, otherwise a `ConfigError::InvalidValue` for the first invalid value.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        fn check(valid: bool, step: ConfigStep, reason: &'static str) -> Result<(), ConfigError> {
+            if valid {
+                Ok(())
+            } else {
+                Err(ConfigError::InvalidValue { step, reason })
+            }
+        }
+
+        check(!self.product_data.trim().is_empty(), ConfigStep::ProductData, "the product data is empty")?;
+        check(!self.product_id.trim().is_empty(), ConfigStep::ProductId, "the product ID is empty")?;
+        if let Some(data_directory) = &self.data_directory {
+            check(Path::new(data_directory).is_absolute(), ConfigStep::DataDirectory, "the path is not absolute")?;
+        }
+        if let Some(device_fingerprint) = &self.custom_device_fingerprint {
+            let length = device_fingerprint.chars().count();
+            check((64..=256).contains(&length), ConfigStep::CustomDeviceFingerprint, "the length must be between 64 and 256 characters")?;
+        }
+        if let Some(proxy) = &self.network_proxy {
+            check(!proxy.is_empty(), ConfigStep::NetworkProxy, "the proxy is empty")?;
+        }
+        if let Some(host) = &self.cryptlex_host {
+            check(host.starts_with("https://") || host.starts_with("http://"), ConfigStep::CryptlexHost, "the host must be an http or https URL")?;
+        }
+        if let Some(version) = &self.release_version {
+            check(is_valid_release_version(version), ConfigStep::ReleaseVersion, "the version must be in the format x.x, x.x.x or x.x.x.x")?;
+        }
+        if let Some(platform) = &self.release_platform {
+            check(platform.chars().count() <= 256, ConfigStep::ReleasePlatform, "the platform is longer than 256 characters")?;
+        }
+        if let Some(channel) = &self.release_channel {
+            check(channel.chars().count() <= 256, ConfigStep::ReleaseChannel, "the channel is longer than 256 characters")?;
+        }
+        if let Some(lease_duration) = self.activation_lease_duration {
+            check(lease_duration >= -1, ConfigStep::ActivationLeaseDuration, "the lease duration must be -1 (unlimited) or positive")?;
+        }
+        validate_metadata(&self.activation_metadata, ConfigStep::ActivationMetadata)?;
+        validate_metadata(&self.trial_activation_metadata, ConfigStep::TrialActivationMetadata)?;
+        if let Some(license_key) = &self.license_key {
+            check(!license_key.trim().is_empty(), ConfigStep::LicenseKey, "the license key is empty")?;
+        }
+        Ok(())
+    }
+
+    /// Validates the configuration and applies it to the LexActivator library.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(LexActivator)` with a handle to the initialised library, If a value is invalid or a step fails, an `Err` containing the `ConfigError` is returned.
+    pub fn build(self) -> Result<LexActivator, ConfigError> {
+        self.build_with_backend(NativeBackend)
+    }
+
+    /// Same as [`LexActivatorConfig::build`], applying the configuration to the given backend.
+    pub fn build_with_backend<B: LicensingBackend>(self, backend: B) -> Result<LexActivator<B>, ConfigError> {
+        self.validate()?;
+        let lexactivator = LexActivator::with_backend(backend);
+        self.apply(lexactivator.backend())?;
+        Ok(lexactivator)
+    }
+
+    fn apply<B: LicensingBackend>(self, backend: &B) -> Result<(), ConfigError> {
+        fn step(step: ConfigStep, result: Result<(), LexActivatorError>) -> Result<(), ConfigError> {
+            result.map_err(|error| ConfigError::StepFailed { step, error })
+        }
+
+        if let Some(enable) = self.debug_mode {
+            backend.set_debug_mode(enable as u32);
+        }
+        step(ConfigStep::ProductData, backend.set_product_data(self.product_data))?;
+        step(ConfigStep::ProductId, backend.set_product_id(self.product_id, self.permission_flags))?;
+        if let Some(data_directory) = self.data_directory {
+            step(ConfigStep::DataDirectory, backend.set_data_directory(data_directory))?;
+        }
+        if let Some(mode) = self.cache_mode {
+            step(ConfigStep::CacheMode, backend.set_cache_mode(mode))?;
+        }
+        if let Some(device_fingerprint) = self.custom_device_fingerprint {
+            step(ConfigStep::CustomDeviceFingerprint, backend.set_custom_device_fingerprint(device_fingerprint))?;
+        }
+        if let Some(proxy) = self.network_proxy {
+            step(ConfigStep::NetworkProxy, backend.set_network_proxy(proxy))?;
+        }
+        if let Some(host) = self.cryptlex_host {
+            step(ConfigStep::CryptlexHost, backend.set_cryptlex_host(host))?;
+        }
+        if let Some(version) = self.release_version {
+            step(ConfigStep::ReleaseVersion, backend.set_release_version(version))?;
+        }
+        if let Some(release_published_date) = self.release_published_date {
+            step(ConfigStep::ReleasePublishedDate, backend.set_release_published_date(release_published_date))?;
+        }
+        if let Some(platform) = self.release_platform {
+            step(ConfigStep::ReleasePlatform, backend.set_release_platform(platform))?;
+        }
+        if let Some(channel) = self.release_channel {
+            step(ConfigStep::ReleaseChannel, backend.set_release_channel(channel))?;
+        }
+        if let Some(lease_duration) = self.activation_lease_duration {
+            step(ConfigStep::ActivationLeaseDuration, backend.set_activation_lease_duration(lease_duration))?;
+        }
+        for (key, value) in self.activation_metadata {
+            step(ConfigStep::ActivationMetadata(key.clone()), backend.set_activation_metadata(key, value))?;
+        }
+        for (key, value) in self.trial_activation_metadata {
+            step(ConfigStep::TrialActivationMetadata(key.clone()), backend.set_trial_activation_metadata(key, value))?;
+        }
+        if let Some(license_key) = self.license_key {
+            step(ConfigStep::LicenseKey, backend.set_license_key(license_key))?;
+        }
+        Ok(())
+    }
+}
+
+fn validate_metadata(metadata: &[(String, String)], step: fn(String) -> ConfigStep) -> Result<(), ConfigError> {
+    for (index, (key, value)) in metadata.iter().enumerate() {
+        let reason = if key.is_empty() {
+            "the metadata key is empty"
+        } else if key.chars().count() > METADATA_KEY_MAX_LENGTH {
+            "the metadata key is longer than 256 characters"
+        } else if value.chars().count() > METADATA_VALUE_MAX_LENGTH {
+            "the metadata value is longer than 4096 characters"
+        } else if metadata[..index].iter().any(|(previous, _)| previous == key) {
+            "the metadata key is set more than once"
+        } else {
+            continue;
+        };
+        return Err(ConfigError::InvalidValue { step: step(key.clone()), reason });
+    }
+    Ok(())
+}
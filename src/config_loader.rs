@@ -0,0 +1,426 @@
+//! Loading a [`LexActivatorConfig`] from a file and `LEXACTIVATOR_*` environment variables.
+//!
+//! The file is a flat JSON object or, with the `toml` feature, a TOML table. The format is chosen by the
+//! file extension. Every key can also be set with an environment variable named `LEXACTIVATOR_` followed
+//! by the key in upper case, e.g. `LEXACTIVATOR_NETWORK_PROXY`. Environment variables take precedence
+//! over the file.
+//!
+//! | Key                          | Type    | Setter                              |
+//! |------------------------------|---------|-------------------------------------|
+//! | `product_data`               | string  | [`set_product_data`]                |
+//! | `product_id`                 | string  | [`set_product_id`]                  |
+//! | `permission_flags`           | string  | `LA_USER`, `LA_SYSTEM`, `LA_ALL_USERS` or `LA_IN_MEMORY` |
+//! | `data_directory`             | string  | [`set_data_directory`]              |
+//! | `debug_mode`                 | boolean | [`set_debug_mode`]                  |
+//! | `cache_mode`                 | boolean | [`set_cache_mode`]                  |
+//! | `custom_device_fingerprint`  | string  | [`set_custom_device_fingerprint`]   |
+//! | `network_proxy`              | string  | [`set_network_proxy`]               |
+//! | `cryptlex_host`              | string  | [`set_cryptlex_host`]               |
+//! | `release_version`            | string  | [`set_release_version`]             |
+//! | `release_published_date`     | integer | [`set_release_published_date`]      |
+//! | `release_platform`           | string  | [`set_release_platform`]            |
+//! | `release_channel`            | string  | [`set_release_channel`]             |
+//! | `activation_lease_duration`  | integer | [`set_activation_lease_duration`]   |
+//! | `license_key`                | string  | [`set_license_key`]                 |
+//!
+//! `product_data` and `product_id` are required. An unknown key in the file, or an environment variable with
+//! the prefix that does not name a key, e.g. `LEXACTIVATOR_PRODUCTID`, is an error. The build script variables
+//! `LEXACTIVATOR_LIB_DIR`, `LEXACTIVATOR_ARCHIVE` and `LEXACTIVATOR_ARCHIVE_SHA256` are ignored. A value that
+//! is not valid UTF-8 is an invalid value.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! // LEXACTIVATOR_NETWORK_PROXY=http://proxy:3128 overrides the proxy of the file.
+//! let lexactivator = LexActivatorConfig::load(Some(Path::new("/etc/my-app/lexactivator.toml")))?.build()?;
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::*;
+
+/// Prefix of the environment variables read by [`LexActivatorConfig::from_env`].
+pub const ENV_PREFIX: &str = "LEXACTIVATOR_";
+
+/// The keys of a configuration, in the order of the table above.
+const KEYS: [&str; 15] = [
+    "product_data",
+    "product_id",
+    "permission_flags",
+    "data_directory",
+    "debug_mode",
+    "cache_mode",
+    "custom_device_fingerprint",
+    "network_proxy",
+    "cryptlex_host",
+    "release_version",
+    "release_published_date",
+    "release_platform",
+    "release_channel",
+    "activation_lease_duration",
+    "license_key",
+];
+
+/// The variables of the build script, which share the prefix but do not name a key.
+const BUILD_ENV_VARS: [&str; 3] = ["LEXACTIVATOR_LIB_DIR", "LEXACTIVATOR_ARCHIVE", "LEXACTIVATOR_ARCHIVE_SHA256"];
+
+/// Returns the configuration key set by the environment variable, or `None` if the variable does not have the
+/// prefix or is a build script variable. Any other variable with the prefix must name a key.
+fn env_key(name: &str) -> Result<Option<String>, ConfigLoadError> {
+    let Some(key) = name.strip_prefix(ENV_PREFIX) else {
+        return Ok(None);
+    };
+    if BUILD_ENV_VARS.contains(&name) {
+        return Ok(None);
+    }
+    let key = key.to_ascii_lowercase();
+    if !KEYS.contains(&key.as_str()) {
+        return Err(ConfigLoadError::UnknownKey { origin: ConfigOrigin::Env(name.to_string()), key });
+    }
+    Ok(Some(key))
+}
+
+/// Where a configuration value was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigOrigin {
+    /// A configuration file.
+    File(PathBuf),
+    /// An environment variable with the given name.
+    Env(String),
+}
+
+impl fmt::Display for ConfigOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigOrigin::File(path) => write!(f, "file {}", path.display()),
+            ConfigOrigin::Env(name) => write!(f, "environment variable {}", name),
+        }
+    }
+}
+
+/// Error returned when a configuration cannot be loaded.
+#[derive(Debug)]
+pub enum ConfigLoadError {
+    /// The file could not be read.
+    Io { path: PathBuf, error: io::Error },
+    /// The file extension is not `.json` or, with the `toml` feature, `.toml`.
+    UnsupportedFormat { path: PathBuf },
+    /// The file is not valid JSON or TOML, or is not a table of keys and values.
+    Parse { path: PathBuf, message: String },
+    /// The key of a configuration file, or the name of a `LEXACTIVATOR_*` environment variable, is not a known setting.
+    UnknownKey { origin: ConfigOrigin, key: String },
+    /// The value of the key has the wrong type or cannot be parsed.
+    InvalidValue { origin: ConfigOrigin, key: String, reason: String },
+    /// A required key is set neither in the file nor in the environment.
+    MissingKey { key: &'static str },
+}
+
+impl fmt::Display for ConfigLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigLoadError::Io { path, error } => write!(f, "Failed to read {}: {}", path.display(), error),
+            ConfigLoadError::UnsupportedFormat { path } => write!(f, "Unsupported configuration file format: {}", path.display()),
+            ConfigLoadError::Parse { path, message } => write!(f, "Failed to parse {}: {}", path.display(), message),
+            ConfigLoadError::UnknownKey { origin, key } => write!(f, "Unknown key {:?} in {}", key, origin),
+            ConfigLoadError::InvalidValue { origin, key, reason } => write!(f, "Invalid value for {:?} in {}: {}", key, origin, reason),
+            ConfigLoadError::MissingKey { key } => write!(f, "Missing required key {:?}", key),
+        }
+    }
+}
+
+impl std::error::Error for ConfigLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigLoadError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A value as read from a file or an environment variable, before it is converted to the type of the key.
+enum RawValue {
+    String(String),
+    Bool(bool),
+    Integer(i64),
+    Other(&'static str),
+}
+
+/// The settings read so far. Later sources overwrite the values of earlier ones.
+#[derive(Default)]
+struct ConfigValues {
+    product_data: Option<String>,
+    product_id: Option<String>,
+    permission_flags: Option<PermissionFlags>,
+    data_directory: Option<String>,
+    debug_mode: Option<bool>,
+    cache_mode: Option<bool>,
+    custom_device_fingerprint: Option<String>,
+    network_proxy: Option<String>,
+    cryptlex_host: Option<String>,
+    release_version: Option<String>,
+    release_published_date: Option<u32>,
+    release_platform: Option<String>,
+    release_channel: Option<String>,
+    activation_lease_duration: Option<i64>,
+    license_key: Option<String>,
+}
+
+impl ConfigValues {
+    fn set(&mut self, origin: &ConfigOrigin, key: &str, value: RawValue) -> Result<(), ConfigLoadError> {
+        let invalid = |reason: String| ConfigLoadError::InvalidValue {
+            origin: origin.clone(),
+            key: key.to_string(),
+            reason,
+        };
+        match key {
+            "product_data" => self.product_data = Some(value.into_string().map_err(invalid)?),
+            "product_id" => self.product_id = Some(value.into_string().map_err(invalid)?),
+            "permission_flags" => self.permission_flags = Some(value.into_permission_flags().map_err(invalid)?),
+            "data_directory" => self.data_directory = Some(value.into_string().map_err(invalid)?),
+            "debug_mode" => self.debug_mode = Some(value.into_bool().map_err(invalid)?),
+            "cache_mode" => self.cache_mode = Some(value.into_bool().map_err(invalid)?),
+            "custom_device_fingerprint" => self.custom_device_fingerprint = Some(value.into_string().map_err(invalid)?),
+            "network_proxy" => self.network_proxy = Some(value.into_string().map_err(invalid)?),
+            "cryptlex_host" => self.cryptlex_host = Some(value.into_string().map_err(invalid)?),
+            "release_version" => self.release_version = Some(value.into_string().map_err(invalid)?),
+            "release_published_date" => self.release_published_date = Some(value.into_integer().map_err(invalid)?),
+            "release_platform" => self.release_platform = Some(value.into_string().map_err(invalid)?),
+            "release_channel" => self.release_channel = Some(value.into_string().map_err(invalid)?),
+            "activation_lease_duration" => self.activation_lease_duration = Some(value.into_integer().map_err(invalid)?),
+            "license_key" => self.license_key = Some(value.into_string().map_err(invalid)?),
+            _ => {
+                return Err(ConfigLoadError::UnknownKey { origin: origin.clone(), key: key.to_string() });
+            }
+        }
+        Ok(())
+    }
+
+    fn read_file(&mut self, path: &Path) -> Result<(), ConfigLoadError> {
+        let content = fs::read_to_string(path).map_err(|error| ConfigLoadError::Io { path: path.to_path_buf(), error })?;
+        let parse_error = |message: String| ConfigLoadError::Parse { path: path.to_path_buf(), message };
+        let entries = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => parse_json_table(&content).map_err(parse_error)?,
+            #[cfg(feature = "toml")]
+            Some("toml") => parse_toml_table(&content).map_err(parse_error)?,
+            _ => return Err(ConfigLoadError::UnsupportedFormat { path: path.to_path_buf() }),
+        };
+        let origin = ConfigOrigin::File(path.to_path_buf());
+        for (key, value) in entries {
+            self.set(&origin, &key, value)?;
+        }
+        Ok(())
+    }
+
+    fn read_env<I>(&mut self, vars: I) -> Result<(), ConfigLoadError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        for (name, value) in vars {
+            if let Some(key) = env_key(&name)? {
+                self.set(&ConfigOrigin::Env(name), &key, RawValue::String(value))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn into_config(self) -> Result<LexActivatorConfig, ConfigLoadError> {
+        let product_data = self.product_data.ok_or(ConfigLoadError::MissingKey { key: "product_data" })?;
+        let product_id = self.product_id.ok_or(ConfigLoadError::MissingKey { key: "product_id" })?;
+        let mut config = LexActivatorConfig::new(product_data, product_id);
+        if let Some(permission_flags) = self.permission_flags {
+            config = config.permission_flags(permission_flags);
+        }
+        if let Some(data_directory) = self.data_directory {
+            config = config.data_directory(data_directory);
+        }
+        if let Some(debug_mode) = self.debug_mode {
+            config = config.debug_mode(debug_mode);
+        }
+        if let Some(cache_mode) = self.cache_mode {
+            config = config.cache_mode(cache_mode);
+        }
+        if let Some(device_fingerprint) = self.custom_device_fingerprint {
+            config = config.custom_device_fingerprint(device_fingerprint);
+        }
+        if let Some(proxy) = self.network_proxy {
+            config = config.network_proxy(proxy);
+        }
+        if let Some(host) = self.cryptlex_host {
+            config = config.cryptlex_host(host);
+        }
+        if let Some(version) = self.release_version {
+            config = config.release_version(version);
+        }
+        if let Some(release_published_date) = self.release_published_date {
+            config = config.release_published_date(release_published_date);
+        }
+        if let Some(platform) = self.release_platform {
+            config = config.release_platform(platform);
+        }
+        if let Some(channel) = self.release_channel {
+            config = config.release_channel(channel);
+        }
+        if let Some(lease_duration) = self.activation_lease_duration {
+            config = config.activation_lease_duration(lease_duration);
+        }
+        if let Some(license_key) = self.license_key {
+            config = config.license_key(license_key);
+        }
+        Ok(config)
+    }
+}
+
+impl RawValue {
+    fn into_string(self) -> Result<String, String> {
+        match self {
+            RawValue::String(value) => Ok(value),
+            other => Err(format!("expected a string, found {}", other.type_name())),
+        }
+    }
+
+    fn into_bool(self) -> Result<bool, String> {
+        match self {
+            RawValue::Bool(value) => Ok(value),
+            RawValue::String(value) => match value.trim().to_ascii_lowercase().as_str() {
+                "true" | "1" | "yes" | "on" => Ok(true),
+                "false" | "0" | "no" | "off" => Ok(false),
+                _ => Err(format!("expected true or false, found {:?}", value)),
+            },
+            other => Err(format!("expected a boolean, found {}", other.type_name())),
+        }
+    }
+
+    fn into_integer<T: TryFrom<i64> + std::str::FromStr>(self) -> Result<T, String> {
+        let out_of_range = |value: &dyn fmt::Display| format!("{} is out of range", value);
+        match self {
+            RawValue::Integer(value) => T::try_from(value).map_err(|_| out_of_range(&value)),
+            RawValue::String(value) => value.trim().parse().map_err(|_| format!("expected an integer, found {:?}", value)),
+            other => Err(format!("expected an integer, found {}", other.type_name())),
+        }
+    }
+
+    fn into_permission_flags(self) -> Result<PermissionFlags, String> {
+        let value = self.into_string()?;
+        let name = value.trim().to_ascii_uppercase();
+        match name.strip_prefix("LA_").unwrap_or(&name) {
+            "USER" => Ok(PermissionFlags::LA_USER),
+            "SYSTEM" => Ok(PermissionFlags::LA_SYSTEM),
+            "ALL_USERS" => Ok(PermissionFlags::LA_ALL_USERS),
+            "IN_MEMORY" => Ok(PermissionFlags::LA_IN_MEMORY),
+            _ => Err(format!("expected LA_USER, LA_SYSTEM, LA_ALL_USERS or LA_IN_MEMORY, found {:?}", value)),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            RawValue::String(_) => "a string",
+            RawValue::Bool(_) => "a boolean",
+            RawValue::Integer(_) => "an integer",
+            RawValue::Other(type_name) => type_name,
+        }
+    }
+}
+
+fn parse_json_table(content: &str) -> Result<Vec<(String, RawValue)>, String> {
+    let value: serde_json::Value = serde_json::from_str(content).map_err(|error| error.to_string())?;
+    let serde_json::Value::Object(table) = value else {
+        return Err("expected a JSON object".to_string());
+    };
+    let entries = table.into_iter().map(|(key, value)| {
+        let value = match value {
+            serde_json::Value::String(value) => RawValue::String(value),
+            serde_json::Value::Bool(value) => RawValue::Bool(value),
+            serde_json::Value::Number(number) => match number.as_i64() {
+                Some(value) => RawValue::Integer(value),
+                None => RawValue::Other("a number that is not an integer"),
+            },
+            serde_json::Value::Null => RawValue::Other("null"),
+            serde_json::Value::Array(_) => RawValue::Other("an array"),
+            serde_json::Value::Object(_) => RawValue::Other("an object"),
+        };
+        (key, value)
+    });
+    Ok(entries.collect())
+}
+
+#[cfg(feature = "toml")]
+fn parse_toml_table(content: &str) -> Result<Vec<(String, RawValue)>, String> {
+    let table: toml::Table = content.parse().map_err(|error: toml::de::Error| error.to_string())?;
+    let entries = table.into_iter().map(|(key, value)| {
+        let value = match value {
+            toml::Value::String(value) => RawValue::String(value),
+            toml::Value::Boolean(value) => RawValue::Bool(value),
+            toml::Value::Integer(value) => RawValue::Integer(value),
+            toml::Value::Float(_) => RawValue::Other("a float"),
+            toml::Value::Datetime(_) => RawValue::Other("a datetime"),
+            toml::Value::Array(_) => RawValue::Other("an array"),
+            toml::Value::Table(_) => RawValue::Other("a table"),
+        };
+        (key, value)
+    });
+    Ok(entries.collect())
+}
+
+impl LexActivatorConfig {
+    /// Reads the configuration from a JSON file or, with the `toml` feature, a TOML file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(LexActivatorConfig)` if the file is read successfully, If an error occurs, an `Err` containing the `ConfigLoadError` is returned.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigLoadError> {
+        let mut values = ConfigValues::default();
+        values.read_file(path.as_ref())?;
+        values.into_config()
+    }
+
+    /// Reads the configuration from the `LEXACTIVATOR_*` environment variables.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(LexActivatorConfig)` if the environment variables are read successfully, If an error occurs, an `Err` containing the `ConfigLoadError` is returned.
+    pub fn from_env() -> Result<Self, ConfigLoadError> {
+        Self::load(None)
+    }
+
+    /// Reads the configuration from an optional file, then from the `LEXACTIVATOR_*` environment variables.
+    /// A key set in the environment overrides the same key in the file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(LexActivatorConfig)` if the configuration is read successfully, If an error occurs, an `Err` containing the `ConfigLoadError` is returned.
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigLoadError> {
+        let mut vars = Vec::new();
+        for (name, value) in std::env::vars_os() {
+            // A variable whose name is not valid UTF-8 cannot set a key.
+            let Ok(name) = name.into_string() else {
+                continue;
+            };
+            match value.into_string() {
+                Ok(value) => vars.push((name, value)),
+                Err(_) => {
+                    if let Some(key) = env_key(&name)? {
+                        let reason = "the value is not valid UTF-8".to_string();
+                        return Err(ConfigLoadError::InvalidValue { origin: ConfigOrigin::Env(name), key, reason });
+                    }
+                }
+            }
+        }
+        Self::load_from(path, vars)
+    }
+
+    /// Same as [`LexActivatorConfig::load`], reading the given variables instead of the environment of the process.
+    pub fn load_from<I>(path: Option<&Path>, vars: I) -> Result<Self, ConfigLoadError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut values = ConfigValues::default();
+        if let Some(path) = path {
+            values.read_file(path)?;
+        }
+        values.read_env(vars)?;
+        values.into_config()
+    }
+}
@@ -0,0 +1,188 @@
+//! Typed access to the values of feature entitlements.
+//!
+//! The LexActivator library returns the value of every [`FeatureEntitlement`] as a string, whatever the type
+//! of the feature. The accessors of this module parse the effective value, or the base value of the
+//! entitlement set, as a boolean, integer, decimal or JSON value. A value that does not parse as the
+//! requested type returns a [`FeatureValueError`].
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! for entitlement in get_feature_entitlements()? {
+//!     if entitlement.is_expired() {
+//!         continue;
+//!     }
+//!     match entitlement.feature_name.as_str() {
+//!         "export" => export_enabled = entitlement.value_as_bool()?,
+//!         "max-projects" => max_projects = entitlement.value_as_i64()?,
+//!         _ => {}
+//!     }
+//! }
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use serde::de::DeserializeOwned;
+
+use crate::*;
+
+/// The type a feature entitlement value was parsed as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureValueType {
+    /// `true` or `false`, in any case.
+    Bool,
+    /// A signed 64-bit integer.
+    Integer,
+    /// A finite decimal number.
+    Decimal,
+    /// A JSON document.
+    Json,
+}
+
+impl fmt::Display for FeatureValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeatureValueType::Bool => write!(f, "a boolean"),
+            FeatureValueType::Integer => write!(f, "an integer"),
+            FeatureValueType::Decimal => write!(f, "a decimal"),
+            FeatureValueType::Json => write!(f, "JSON"),
+        }
+    }
+}
+
+/// The value of a feature entitlement does not parse as the requested type.
+#[derive(Debug, Clone)]
+pub struct FeatureValueError {
+    feature_name: String,
+    value: String,
+    expected: FeatureValueType,
+    json_error: Option<Arc<serde_json::Error>>,
+}
+
+impl FeatureValueError {
+    fn new(feature_name: &str, value: &str, expected: FeatureValueType) -> Self {
+        FeatureValueError {
+            feature_name: feature_name.to_string(),
+            value: value.to_string(),
+            expected,
+            json_error: None,
+        }
+    }
+
+    /// Returns the name of the feature.
+    pub fn feature_name(&self) -> &str {
+        &self.feature_name
+    }
+
+    /// Returns the value that failed to parse.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the type the value was parsed as.
+    pub fn expected(&self) -> FeatureValueType {
+        self.expected
+    }
+}
+
+impl fmt::Display for FeatureValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Value {:?} of feature {} is not {}", self.value, self.feature_name, self.expected)?;
+        if let Some(json_error) = &self.json_error {
+            write!(f, ": {}", json_error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FeatureValueError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.json_error.as_ref().map(|error| error.as_ref() as &(dyn std::error::Error + 'static))
+    }
+}
+
+fn parse_bool(feature_name: &str, value: &str) -> Result<bool, FeatureValueError> {
+    match value.trim() {
+        value if value.eq_ignore_ascii_case("true") => Ok(true),
+        value if value.eq_ignore_ascii_case("false") => Ok(false),
+        _ => Err(FeatureValueError::new(feature_name, value, FeatureValueType::Bool)),
+    }
+}
+
+fn parse_i64(feature_name: &str, value: &str) -> Result<i64, FeatureValueError> {
+    value.trim().parse().map_err(|_| FeatureValueError::new(feature_name, value, FeatureValueType::Integer))
+}
+
+fn parse_f64(feature_name: &str, value: &str) -> Result<f64, FeatureValueError> {
+    match value.trim().parse::<f64>() {
+        Ok(decimal) if decimal.is_finite() => Ok(decimal),
+        _ => Err(FeatureValueError::new(feature_name, value, FeatureValueType::Decimal)),
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(feature_name: &str, value: &str) -> Result<T, FeatureValueError> {
+    serde_json::from_str(value).map_err(|error| FeatureValueError {
+        json_error: Some(Arc::new(error)),
+        ..FeatureValueError::new(feature_name, value, FeatureValueType::Json)
+    })
+}
+
+impl FeatureEntitlement {
+    /// Returns the default value of the feature defined in the entitlement set; empty for features not inherited from an entitlement set.
+    pub fn base_value(&self) -> &str {
+        &self.baseValue
+    }
+
+    /// Returns `true` if the effective value differs from the base value, i.e. if it was overridden at the
+    /// license level. Features not inherited from an entitlement set have an empty base value and count as overridden.
+    pub fn is_overridden(&self) -> bool {
+        self.value != self.baseValue
+    }
+
+    /// Returns `true` if the feature entitlement has an expiry date that has passed.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at_time().is_some_and(|expires_at| expires_at <= SystemTime::now())
+    }
+
+    /// Parses the effective value as a boolean, `true` or `false` in any case.
+    pub fn value_as_bool(&self) -> Result<bool, FeatureValueError> {
+        parse_bool(&self.feature_name, &self.value)
+    }
+
+    /// Parses the effective value as an integer.
+    pub fn value_as_i64(&self) -> Result<i64, FeatureValueError> {
+        parse_i64(&self.feature_name, &self.value)
+    }
+
+    /// Parses the effective value as a finite decimal number.
+    pub fn value_as_f64(&self) -> Result<f64, FeatureValueError> {
+        parse_f64(&self.feature_name, &self.value)
+    }
+
+    /// Parses the effective value as JSON, e.g. into a `serde_json::Value` or a struct that implements `Deserialize`.
+    pub fn value_as_json<T: DeserializeOwned>(&self) -> Result<T, FeatureValueError> {
+        parse_json(&self.feature_name, &self.value)
+    }
+
+    /// Parses the base value as a boolean, `true` or `false` in any case.
+    pub fn base_value_as_bool(&self) -> Result<bool, FeatureValueError> {
+        parse_bool(&self.feature_name, &self.baseValue)
+    }
+
+    /// Parses the base value as an integer.
+    pub fn base_value_as_i64(&self) -> Result<i64, FeatureValueError> {
+        parse_i64(&self.feature_name, &self.baseValue)
+    }
+
+    /// Parses the base value as a finite decimal number.
+    pub fn base_value_as_f64(&self) -> Result<f64, FeatureValueError> {
+        parse_f64(&self.feature_name, &self.baseValue)
+    }
+
+    /// Parses the base value as JSON, e.g. into a `serde_json::Value` or a struct that implements `Deserialize`.
+    pub fn base_value_as_json<T: DeserializeOwned>(&self) -> Result<T, FeatureValueError> {
+        parse_json(&self.feature_name, &self.baseValue)
+    }
+}
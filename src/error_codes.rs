@@ -2,8 +2,9 @@
 
 use std::fmt;
 use std::ffi::NulError;
+use std::sync::Arc;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[derive(PartialEq)]
 #[repr(i32)]
 pub enum LexActivatorStatus {
@@ -27,9 +28,11 @@ pub enum LexActivatorStatus {
     LA_RELEASE_UPDATE_NOT_AVAILABLE = 31,
     /// The update available is not allowed for this license.
     LA_RELEASE_UPDATE_AVAILABLE_NOT_ALLOWED = 32,
+    /// A status code that is not known to this version of the crate.
+    Unknown(i32),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[derive(PartialEq)]
 #[repr(i32)]
 pub enum LexActivatorError {
@@ -165,6 +168,264 @@ pub enum LexActivatorError {
     LA_E_ENTITLEMENT_SET_NOT_LINKED = 109,
     /// The license cannot be activated before its effective date.
     LA_E_LICENSE_NOT_EFFECTIVE = 110,
+    /// An error code that is not known to this version of the crate.
+    Unknown(i32),
+    /// The JSON returned by the LexActivator library could not be parsed. Its code is
+    /// [`LexActivatorError::JSON_PARSE_CODE`].
+    JsonParse(JsonParseError),
+    /// The named method is not implemented by the [`LicensingBackend`](crate::LicensingBackend) in use. Its code is
+    /// [`LexActivatorError::NOT_IMPLEMENTED_CODE`].
+    NotImplemented(&'static str),
+}
+
+impl LexActivatorStatus {
+    /// Returns the numeric status code as returned by the LexActivator library.
+    pub fn code(&self) -> i32 {
+        match self {
+            LexActivatorStatus::LA_OK => 0,
+            LexActivatorStatus::LA_FAIL => 1,
+            LexActivatorStatus::LA_EXPIRED => 20,
+            LexActivatorStatus::LA_SUSPENDED => 21,
+            LexActivatorStatus::LA_GRACE_PERIOD_OVER => 22,
+            LexActivatorStatus::LA_TRIAL_EXPIRED => 25,
+            LexActivatorStatus::LA_LOCAL_TRIAL_EXPIRED => 26,
+            LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE => 30,
+            LexActivatorStatus::LA_RELEASE_UPDATE_NOT_AVAILABLE => 31,
+            LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE_NOT_ALLOWED => 32,
+            LexActivatorStatus::Unknown(code) => *code,
+        }
+    }
+
+    /// Returns the description of the status code.
+    pub fn message(&self) -> &'static str {
+        match self {
+            LexActivatorStatus::LA_OK => "Success code.",
+            LexActivatorStatus::LA_FAIL => "Failure code.",
+            LexActivatorStatus::LA_EXPIRED => "The license has expired or system time has been tampered with. Ensure your date and time settings are correct.",
+            LexActivatorStatus::LA_SUSPENDED => "The license has been suspended.",
+            LexActivatorStatus::LA_GRACE_PERIOD_OVER => "The grace period for server sync is over.",
+            LexActivatorStatus::LA_TRIAL_EXPIRED => "The trial has expired or system time has been tampered with. Ensure your date and time settings are correct.",
+            LexActivatorStatus::LA_LOCAL_TRIAL_EXPIRED => "The local trial has expired or system time has been tampered with. Ensure your date and time settings are correct.",
+            LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE => "A new update is available for the product. This means a new release has been published for the product.",
+            LexActivatorStatus::LA_RELEASE_UPDATE_NOT_AVAILABLE => "No new update is available for the product. The current version is latest.",
+            LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE_NOT_ALLOWED => "The update available is not allowed for this license.",
+            LexActivatorStatus::Unknown(_) => "Unknown status code.",
+        }
+    }
+}
+
+impl LexActivatorError {
+    /// The code of [`LexActivatorError::JsonParse`]. It is raised by this crate, not by the LexActivator library,
+    /// and is negative so that it does not collide with the codes of the library.
+    pub const JSON_PARSE_CODE: i32 = -1;
+
+    /// The code of [`LexActivatorError::NotImplemented`], negative like [`LexActivatorError::JSON_PARSE_CODE`].
+    pub const NOT_IMPLEMENTED_CODE: i32 = -2;
+
+    /// Returns the numeric error code as returned by the LexActivator library.
+    ///
+    /// The errors raised by this crate have the negative codes [`LexActivatorError::JSON_PARSE_CODE`] and
+    /// [`LexActivatorError::NOT_IMPLEMENTED_CODE`]. As they carry details that the code does not, converting these
+    /// codes back with `LexActivatorError::from` returns `Unknown` with the code.
+    pub fn code(&self) -> i32 {
+        match self {
+            LexActivatorError::LA_FAIL => 1,
+            LexActivatorError::LA_E_FILE_PATH => 40,
+            LexActivatorError::LA_E_PRODUCT_FILE => 41,
+            LexActivatorError::LA_E_PRODUCT_DATA => 42,
+            LexActivatorError::LA_E_PRODUCT_ID => 43,
+            LexActivatorError::LA_E_SYSTEM_PERMISSION => 44,
+            LexActivatorError::LA_E_FILE_PERMISSION => 45,
+            LexActivatorError::LA_E_WMIC => 46,
+            LexActivatorError::LA_E_TIME => 47,
+            LexActivatorError::LA_E_INET => 48,
+            LexActivatorError::LA_E_NET_PROXY => 49,
+            LexActivatorError::LA_E_HOST_URL => 50,
+            LexActivatorError::LA_E_BUFFER_SIZE => 51,
+            LexActivatorError::LA_E_APP_VERSION_LENGTH => 52,
+            LexActivatorError::LA_E_REVOKED => 53,
+            LexActivatorError::LA_E_LICENSE_KEY => 54,
+            LexActivatorError::LA_E_LICENSE_TYPE => 55,
+            LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE => 56,
+            LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE_EXPIRED => 57,
+            LexActivatorError::LA_E_ACTIVATION_LIMIT => 58,
+            LexActivatorError::LA_E_ACTIVATION_NOT_FOUND => 59,
+            LexActivatorError::LA_E_DEACTIVATION_LIMIT => 60,
+            LexActivatorError::LA_E_TRIAL_NOT_ALLOWED => 61,
+            LexActivatorError::LA_E_TRIAL_ACTIVATION_LIMIT => 62,
+            LexActivatorError::LA_E_MACHINE_FINGERPRINT => 63,
+            LexActivatorError::LA_E_METADATA_KEY_LENGTH => 64,
+            LexActivatorError::LA_E_METADATA_VALUE_LENGTH => 65,
+            LexActivatorError::LA_E_ACTIVATION_METADATA_LIMIT => 66,
+            LexActivatorError::LA_E_TRIAL_ACTIVATION_METADATA_LIMIT => 67,
+            LexActivatorError::LA_E_METADATA_KEY_NOT_FOUND => 68,
+            LexActivatorError::LA_E_TIME_MODIFIED => 69,
+            LexActivatorError::LA_E_RELEASE_VERSION_FORMAT => 70,
+            LexActivatorError::LA_E_AUTHENTICATION_FAILED => 71,
+            LexActivatorError::LA_E_METER_ATTRIBUTE_NOT_FOUND => 72,
+            LexActivatorError::LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED => 73,
+            LexActivatorError::LA_E_CUSTOM_FINGERPRINT_LENGTH => 74,
+            LexActivatorError::LA_E_PRODUCT_VERSION_NOT_LINKED => 75,
+            LexActivatorError::LA_E_FEATURE_FLAG_NOT_FOUND => 76,
+            LexActivatorError::LA_E_RELEASE_VERSION_NOT_ALLOWED => 77,
+            LexActivatorError::LA_E_RELEASE_PLATFORM_LENGTH => 78,
+            LexActivatorError::LA_E_RELEASE_CHANNEL_LENGTH => 79,
+            LexActivatorError::LA_E_VM => 80,
+            LexActivatorError::LA_E_COUNTRY => 81,
+            LexActivatorError::LA_E_IP => 82,
+            LexActivatorError::LA_E_CONTAINER => 83,
+            LexActivatorError::LA_E_RELEASE_VERSION => 84,
+            LexActivatorError::LA_E_RELEASE_PLATFORM => 85,
+            LexActivatorError::LA_E_RELEASE_CHANNEL => 86,
+            LexActivatorError::LA_E_USER_NOT_AUTHENTICATED => 87,
+            LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_MISSING => 88,
+            LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_INVALID => 89,
+            LexActivatorError::LA_E_RATE_LIMIT => 90,
+            LexActivatorError::LA_E_SERVER => 91,
+            LexActivatorError::LA_E_CLIENT => 92,
+            LexActivatorError::LA_E_ACCOUNT_ID => 93,
+            LexActivatorError::LA_E_LOGIN_TEMPORARILY_LOCKED => 100,
+            LexActivatorError::LA_E_AUTHENTICATION_ID_TOKEN_INVALID => 101,
+            LexActivatorError::LA_E_OIDC_SSO_NOT_ENABLED => 102,
+            LexActivatorError::LA_E_USERS_LIMIT_REACHED => 103,
+            LexActivatorError::LA_E_OS_USER => 104,
+            LexActivatorError::LA_E_INVALID_PERMISSION_FLAG => 105,
+            LexActivatorError::LA_E_FREE_PLAN_ACTIVATION_LIMIT_REACHED => 106,
+            LexActivatorError::LA_E_FEATURE_ENTITLEMENTS_INVALID => 107,
+            LexActivatorError::LA_E_FEATURE_ENTITLEMENT_NOT_FOUND => 108,
+            LexActivatorError::LA_E_ENTITLEMENT_SET_NOT_LINKED => 109,
+            LexActivatorError::LA_E_LICENSE_NOT_EFFECTIVE => 110,
+            LexActivatorError::Unknown(code) => *code,
+            LexActivatorError::JsonParse(_) => LexActivatorError::JSON_PARSE_CODE,
+            LexActivatorError::NotImplemented(_) => LexActivatorError::NOT_IMPLEMENTED_CODE,
+        }
+    }
+
+    /// Returns the description of the error code.
+    pub fn message(&self) -> &'static str {
+        match self {
+            LexActivatorError::LA_FAIL => "Failure code.",
+            LexActivatorError::LA_E_FILE_PATH => "Invalid file path.",
+            LexActivatorError::LA_E_PRODUCT_FILE => "Invalid or corrupted product file.",
+            LexActivatorError::LA_E_PRODUCT_DATA => "Invalid product data.",
+            LexActivatorError::LA_E_PRODUCT_ID => "The product id is incorrect.",
+            LexActivatorError::LA_E_SYSTEM_PERMISSION => "Insufficent system permissions.",
+            LexActivatorError::LA_E_FILE_PERMISSION => "No permission to write to file.",
+            LexActivatorError::LA_E_WMIC => "Fingerprint couldn't be generated because Windows Management Instrumentation (WMI) service has been disabled.",
+            LexActivatorError::LA_E_TIME => "The difference between the network time and the system time is more than allowed clock offset.",
+            LexActivatorError::LA_E_INET => "Failed to connect to the server due to network error.",
+            LexActivatorError::LA_E_NET_PROXY => "Invalid network proxy.",
+            LexActivatorError::LA_E_HOST_URL => "Invalid Cryptlex host url.",
+            LexActivatorError::LA_E_BUFFER_SIZE => "The buffer size was smaller than required.",
+            LexActivatorError::LA_E_APP_VERSION_LENGTH => "App version length is more than characters.",
+            LexActivatorError::LA_E_REVOKED => "The license has been revoked.",
+            LexActivatorError::LA_E_LICENSE_KEY => "Invalid license key.",
+            LexActivatorError::LA_E_LICENSE_TYPE => "Invalid license type. Make sure floating license is not being used.",
+            LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE => "Invalid offline activation response file.",
+            LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE_EXPIRED => "The offline activation response has expired.",
+            LexActivatorError::LA_E_ACTIVATION_LIMIT => "The license has reached it's allowed activations limit.",
+            LexActivatorError::LA_E_ACTIVATION_NOT_FOUND => "The license activation was deleted on the server.",
+            LexActivatorError::LA_E_DEACTIVATION_LIMIT => "The license has reached it's allowed deactivations limit.",
+            LexActivatorError::LA_E_TRIAL_NOT_ALLOWED => "Trial not allowed for the product.",
+            LexActivatorError::LA_E_TRIAL_ACTIVATION_LIMIT => "Your account has reached it's trial activations limit and trial not allowed for the product.",
+            LexActivatorError::LA_E_MACHINE_FINGERPRINT => "Machine fingerprint has changed since activation.",
+            LexActivatorError::LA_E_METADATA_KEY_LENGTH => "Metadata key length is more than 256 characters.",
+            LexActivatorError::LA_E_METADATA_VALUE_LENGTH => "Metadata value length is more than 256 characters.",
+            LexActivatorError::LA_E_ACTIVATION_METADATA_LIMIT => "The license has reached it's metadata fields limit.",
+            LexActivatorError::LA_E_TRIAL_ACTIVATION_METADATA_LIMIT => "The trial has reached it's metadata fields limit.",
+            LexActivatorError::LA_E_METADATA_KEY_NOT_FOUND => "The metadata key does not exist.",
+            LexActivatorError::LA_E_TIME_MODIFIED => "The system time has been tampered (backdated).",
+            LexActivatorError::LA_E_RELEASE_VERSION_FORMAT => "Invalid version format.",
+            LexActivatorError::LA_E_AUTHENTICATION_FAILED => "Incorrect email or password.",
+            LexActivatorError::LA_E_METER_ATTRIBUTE_NOT_FOUND => "The meter attribute does not exist.",
+            LexActivatorError::LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED => "The meter attribute has reached it's usage limit.",
+            LexActivatorError::LA_E_CUSTOM_FINGERPRINT_LENGTH => "Custom device fingerprint length is less than 64 characters or more than 256 characters.",
+            LexActivatorError::LA_E_PRODUCT_VERSION_NOT_LINKED => "No product version is linked with the license.",
+            LexActivatorError::LA_E_FEATURE_FLAG_NOT_FOUND => "The product version feature flag does not exist.",
+            LexActivatorError::LA_E_RELEASE_VERSION_NOT_ALLOWED => "The release version is not allowed.",
+            LexActivatorError::LA_E_RELEASE_PLATFORM_LENGTH => "Release platform length is more than 256 characters.",
+            LexActivatorError::LA_E_RELEASE_CHANNEL_LENGTH => "Release channel length is more than 256 characters.",
+            LexActivatorError::LA_E_VM => "Application is running inside virtual machine / hypervisor and activation has been disallowed in the VM.",
+            LexActivatorError::LA_E_COUNTRY => "Country is not allowed.",
+            LexActivatorError::LA_E_IP => "IP address is not allowed.",
+            LexActivatorError::LA_E_CONTAINER => "Application is being run inside a container and activation has been disallowed in the container.",
+            LexActivatorError::LA_E_RELEASE_VERSION => "Invalid release version. Make sure the release version uses the following formats: x.x, x.x.x, x.x.x.x (where x is a number).",
+            LexActivatorError::LA_E_RELEASE_PLATFORM => "Release platform not set.",
+            LexActivatorError::LA_E_RELEASE_CHANNEL => "Release channel not set.",
+            LexActivatorError::LA_E_USER_NOT_AUTHENTICATED => "The user is not authenticated.",
+            LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_MISSING => "The two-factor authentication code for the user authentication is missing.",
+            LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_INVALID => "he two-factor authentication code provided by the user is invalid.",
+            LexActivatorError::LA_E_RATE_LIMIT => "Rate limit for API has reached, try again later.",
+            LexActivatorError::LA_E_SERVER => "Server error.",
+            LexActivatorError::LA_E_CLIENT => "Client error.",
+            LexActivatorError::LA_E_LOGIN_TEMPORARILY_LOCKED => "The user account has been temporarily locked for 5 mins due to 5 failed attempts.",
+            LexActivatorError::LA_E_AUTHENTICATION_ID_TOKEN_INVALID => "Invalid authentication ID token.",
+            LexActivatorError::LA_E_OIDC_SSO_NOT_ENABLED => "OIDC SSO is not enabled.",
+            LexActivatorError::LA_E_USERS_LIMIT_REACHED => "The allowed users for this account has reached its limit.",
+            LexActivatorError::LA_E_OS_USER => "OS user has changed since activation and the license is user-locked.",
+            LexActivatorError::LA_E_INVALID_PERMISSION_FLAG => "Invalid permission flag.",
+            LexActivatorError::LA_E_FREE_PLAN_ACTIVATION_LIMIT_REACHED => "The free plan has reached its activation limit.",
+            LexActivatorError::LA_E_ACCOUNT_ID => "Invalid account ID.",
+            LexActivatorError::LA_E_FEATURE_ENTITLEMENTS_INVALID => "Invalid feature entitlements.",
+            LexActivatorError::LA_E_FEATURE_ENTITLEMENT_NOT_FOUND => "The feature entitlement does not exist.",
+            LexActivatorError::LA_E_ENTITLEMENT_SET_NOT_LINKED => "No entitlement set is linked to the license.",
+            LexActivatorError::LA_E_LICENSE_NOT_EFFECTIVE => "The license cannot be activated before its effective date.",
+            LexActivatorError::Unknown(_) => "Unknown error code.",
+            LexActivatorError::JsonParse(_) => "Failed to parse the JSON returned by the LexActivator library.",
+            LexActivatorError::NotImplemented(_) => "The function is not implemented by the licensing backend.",
+        }
+    }
+
+    /// Returns `true` if the error was caused by the network or the Cryptlex servers.
+    pub fn is_network_related(&self) -> bool {
+        matches!(
+            self,
+            LexActivatorError::LA_E_INET
+                | LexActivatorError::LA_E_NET_PROXY
+                | LexActivatorError::LA_E_HOST_URL
+                | LexActivatorError::LA_E_RATE_LIMIT
+                | LexActivatorError::LA_E_SERVER
+        )
+    }
+
+    /// Returns `true` if the same call may succeed when it is retried later without any other change.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            LexActivatorError::LA_E_INET
+                | LexActivatorError::LA_E_RATE_LIMIT
+                | LexActivatorError::LA_E_SERVER
+                | LexActivatorError::LA_E_LOGIN_TEMPORARILY_LOCKED
+        )
+    }
+
+    /// Returns `true` if the user has to act before the call can succeed, e.g. by correcting the license key or
+    /// credentials, fixing the system clock or running the application with the required permissions.
+    pub fn requires_user_action(&self) -> bool {
+        matches!(
+            self,
+            LexActivatorError::LA_E_SYSTEM_PERMISSION
+                | LexActivatorError::LA_E_FILE_PERMISSION
+                | LexActivatorError::LA_E_WMIC
+                | LexActivatorError::LA_E_TIME
+                | LexActivatorError::LA_E_NET_PROXY
+                | LexActivatorError::LA_E_LICENSE_KEY
+                | LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE
+                | LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE_EXPIRED
+                | LexActivatorError::LA_E_ACTIVATION_LIMIT
+                | LexActivatorError::LA_E_ACTIVATION_NOT_FOUND
+                | LexActivatorError::LA_E_DEACTIVATION_LIMIT
+                | LexActivatorError::LA_E_MACHINE_FINGERPRINT
+                | LexActivatorError::LA_E_TIME_MODIFIED
+                | LexActivatorError::LA_E_AUTHENTICATION_FAILED
+                | LexActivatorError::LA_E_USER_NOT_AUTHENTICATED
+                | LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_MISSING
+                | LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_INVALID
+                | LexActivatorError::LA_E_LOGIN_TEMPORARILY_LOCKED
+                | LexActivatorError::LA_E_AUTHENTICATION_ID_TOKEN_INVALID
+                | LexActivatorError::LA_E_OS_USER
+        )
+    }
 }
 
 impl From<i32> for LexActivatorStatus {
@@ -180,7 +441,7 @@ impl From<i32> for LexActivatorStatus {
             30 => LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE,
             31 => LexActivatorStatus::LA_RELEASE_UPDATE_NOT_AVAILABLE,
             32 => LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE_NOT_ALLOWED,
-            _ => todo!(),
+            _ => LexActivatorStatus::Unknown(code),
         }  
     }
 }
@@ -254,109 +515,78 @@ impl From<i32> for LexActivatorError {
             108 => LexActivatorError::LA_E_FEATURE_ENTITLEMENT_NOT_FOUND,
             109 => LexActivatorError::LA_E_ENTITLEMENT_SET_NOT_LINKED,
             110 => LexActivatorError::LA_E_LICENSE_NOT_EFFECTIVE,
-            _ => todo!(),
-            // Add more mappings as needed
+            _ => LexActivatorError::Unknown(code),
         }
     }
 }
 
 impl fmt::Display for LexActivatorStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            LexActivatorStatus::LA_OK => write!(f, "{} Success code.", LexActivatorStatus::LA_OK as i32),
-            LexActivatorStatus::LA_FAIL => write!(f, "{} Failure code.", LexActivatorStatus::LA_FAIL as i32),
-            LexActivatorStatus::LA_EXPIRED => write!(f, "{} The license has expired or system time has been tampered with. Ensure your date and time settings are correct.", LexActivatorStatus::LA_EXPIRED as i32),
-            LexActivatorStatus::LA_SUSPENDED => write!(f, "{} The license has been suspended.", LexActivatorStatus::LA_SUSPENDED as i32),
-            LexActivatorStatus::LA_GRACE_PERIOD_OVER => write!(f, "{} The grace period for server sync is over.", LexActivatorStatus::LA_GRACE_PERIOD_OVER as i32),
-            LexActivatorStatus::LA_TRIAL_EXPIRED => write!(f, "{} The trial has expired or system time has been tampered with. Ensure your date and time settings are correct.", LexActivatorStatus::LA_TRIAL_EXPIRED as i32),
-            LexActivatorStatus::LA_LOCAL_TRIAL_EXPIRED => write!(f, "{} The local trial has expired or system time has been tampered with. Ensure your date and time settings are correct.", LexActivatorStatus::LA_LOCAL_TRIAL_EXPIRED as i32),
-            LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE => write!(f, "{} A new update is available for the product. This means a new release has been published for the product.", LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE as i32),
-            LexActivatorStatus::LA_RELEASE_UPDATE_NOT_AVAILABLE => write!(f, "{} No new update is available for the product. The current version is latest.", LexActivatorStatus::LA_RELEASE_UPDATE_NOT_AVAILABLE as i32),
-            LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE_NOT_ALLOWED => write!(f, "{} The update available is not allowed for this license.", LexActivatorStatus::LA_RELEASE_UPDATE_AVAILABLE_NOT_ALLOWED as i32),
-        }
+        write!(f, "{} {}", self.code(), self.message())
     }
-}     
+}
 
 impl fmt::Display for LexActivatorError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            LexActivatorError::LA_FAIL => write!(f, "{} Failure code.", LexActivatorError::LA_FAIL as i32),
-            LexActivatorError::LA_E_FILE_PATH => write!(f, "{} Invalid file path.", LexActivatorError::LA_E_FILE_PATH as i32),
-            LexActivatorError::LA_E_PRODUCT_FILE => write!(f, "{} Invalid or corrupted product file.", LexActivatorError::LA_E_PRODUCT_FILE as i32),
-            LexActivatorError::LA_E_PRODUCT_DATA => write!(f, "{} Invalid product data.", LexActivatorError::LA_E_PRODUCT_DATA as i32),
-            LexActivatorError::LA_E_PRODUCT_ID => write!(f, "{} The product id is incorrect.", LexActivatorError::LA_E_PRODUCT_ID as i32),
-            LexActivatorError::LA_E_SYSTEM_PERMISSION => write!(f, "{} Insufficent system permissions.", LexActivatorError::LA_E_SYSTEM_PERMISSION as i32),
-            LexActivatorError::LA_E_FILE_PERMISSION => write!(f, "{} No permission to write to file.", LexActivatorError::LA_E_FILE_PERMISSION as i32),
-            LexActivatorError::LA_E_WMIC => write!(f, "{} Fingerprint couldn't be generated because Windows Management Instrumentation (WMI) service has been disabled.", LexActivatorError::LA_E_WMIC as i32),
-            LexActivatorError::LA_E_TIME => write!(f, "{} The difference between the network time and the system time is more than allowed clock offset.", LexActivatorError::LA_E_TIME as i32),
-            LexActivatorError::LA_E_INET => write!(f, "{} Failed to connect to the server due to network error.", LexActivatorError::LA_E_INET as i32),
-            LexActivatorError::LA_E_NET_PROXY => write!(f, "{} Invalid network proxy.", LexActivatorError::LA_E_NET_PROXY as i32),
-            LexActivatorError::LA_E_HOST_URL => write!(f, "{} Invalid Cryptlex host url.", LexActivatorError::LA_E_HOST_URL as i32),
-            LexActivatorError::LA_E_BUFFER_SIZE => write!(f, "{} The buffer size was smaller than required.", LexActivatorError::LA_E_BUFFER_SIZE as i32),
-            LexActivatorError::LA_E_APP_VERSION_LENGTH => write!(f, "{} App version length is more than characters.", LexActivatorError::LA_E_APP_VERSION_LENGTH as i32),
-            LexActivatorError::LA_E_REVOKED => write!(f, "{} The license has been revoked.", LexActivatorError::LA_E_REVOKED as i32),
-            LexActivatorError::LA_E_LICENSE_KEY => write!(f, "{} Invalid license key.", LexActivatorError::LA_E_LICENSE_KEY as i32),
-            LexActivatorError::LA_E_LICENSE_TYPE => write!(f, "{} Invalid license type. Make sure floating license is not being used.", LexActivatorError::LA_E_LICENSE_TYPE as i32),
-            LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE => write!(f, "{} Invalid offline activation response file.", LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE as i32),
-            LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE_EXPIRED => write!(f, "{} The offline activation response has expired.", LexActivatorError::LA_E_OFFLINE_RESPONSE_FILE_EXPIRED as i32),
-            LexActivatorError::LA_E_ACTIVATION_LIMIT => write!(f, "{} The license has reached it's allowed activations limit.", LexActivatorError::LA_E_ACTIVATION_LIMIT as i32),
-            LexActivatorError::LA_E_ACTIVATION_NOT_FOUND => write!(f, "{} The license activation was deleted on the server.", LexActivatorError::LA_E_ACTIVATION_NOT_FOUND as i32),
-            LexActivatorError::LA_E_DEACTIVATION_LIMIT => write!(f, "{} The license has reached it's allowed deactivations limit.", LexActivatorError::LA_E_DEACTIVATION_LIMIT as i32),
-            LexActivatorError::LA_E_TRIAL_NOT_ALLOWED => write!(f, "{} Trial not allowed for the product.", LexActivatorError::LA_E_TRIAL_NOT_ALLOWED as i32),
-            LexActivatorError::LA_E_TRIAL_ACTIVATION_LIMIT => write!(f, "{} Your account has reached it's trial activations limit and trial not allowed for the product.", LexActivatorError::LA_E_TRIAL_ACTIVATION_LIMIT as i32),
-            LexActivatorError::LA_E_MACHINE_FINGERPRINT => write!(f, "{} Machine fingerprint has changed since activation.", LexActivatorError::LA_E_MACHINE_FINGERPRINT as i32),
-            LexActivatorError::LA_E_METADATA_KEY_LENGTH => write!(f, "{} Metadata key length is more than 256 characters.", LexActivatorError::LA_E_METADATA_KEY_LENGTH as i32),
-            LexActivatorError::LA_E_METADATA_VALUE_LENGTH => write!(f, "{} Metadata value length is more than 256 characters.", LexActivatorError::LA_E_METADATA_VALUE_LENGTH as i32),
-            LexActivatorError::LA_E_ACTIVATION_METADATA_LIMIT => write!(f, "{} The license has reached it's metadata fields limit.", LexActivatorError::LA_E_ACTIVATION_METADATA_LIMIT as i32),
-            LexActivatorError::LA_E_TRIAL_ACTIVATION_METADATA_LIMIT => write!(f, "{} The trial has reached it's metadata fields limit.", LexActivatorError::LA_E_TRIAL_ACTIVATION_METADATA_LIMIT as i32),
-            LexActivatorError::LA_E_METADATA_KEY_NOT_FOUND => write!(f, "{} The metadata key does not exist.", LexActivatorError::LA_E_METADATA_KEY_NOT_FOUND as i32),
-            LexActivatorError::LA_E_TIME_MODIFIED => write!(f, "{} The system time has been tampered (backdated).", LexActivatorError::LA_E_TIME_MODIFIED as i32),
-            LexActivatorError::LA_E_RELEASE_VERSION_FORMAT => write!(f, "{} Invalid version format.", LexActivatorError::LA_E_RELEASE_VERSION_FORMAT as i32),
-            LexActivatorError::LA_E_AUTHENTICATION_FAILED => write!(f, "{} Incorrect email or password.", LexActivatorError::LA_E_AUTHENTICATION_FAILED as i32),
-            LexActivatorError::LA_E_METER_ATTRIBUTE_NOT_FOUND => write!(f, "{} The meter attribute does not exist.", LexActivatorError::LA_E_METER_ATTRIBUTE_NOT_FOUND as i32),
-            LexActivatorError::LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED => write!(f, "{} The meter attribute has reached it's usage limit.", LexActivatorError::LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED as i32),
-            LexActivatorError::LA_E_CUSTOM_FINGERPRINT_LENGTH => write!(f, "{} Custom device fingerprint length is less than 64 characters or more than 256 characters.", LexActivatorError::LA_E_CUSTOM_FINGERPRINT_LENGTH as i32),
-            LexActivatorError::LA_E_PRODUCT_VERSION_NOT_LINKED => write!(f, "{} No product version is linked with the license.", LexActivatorError::LA_E_PRODUCT_VERSION_NOT_LINKED as i32),
-            LexActivatorError::LA_E_FEATURE_FLAG_NOT_FOUND => write!(f, "{} The product version feature flag does not exist.", LexActivatorError::LA_E_FEATURE_FLAG_NOT_FOUND as i32),
-            LexActivatorError::LA_E_RELEASE_VERSION_NOT_ALLOWED => write!(f, "{} The release version is not allowed.", LexActivatorError::LA_E_RELEASE_VERSION_NOT_ALLOWED as i32),
-            LexActivatorError::LA_E_RELEASE_PLATFORM_LENGTH => write!(f, "{} Release platform length is more than 256 characters.", LexActivatorError::LA_E_RELEASE_PLATFORM_LENGTH as i32),
-            LexActivatorError::LA_E_RELEASE_CHANNEL_LENGTH => write!(f, "{} Release channel length is more than 256 characters.", LexActivatorError::LA_E_RELEASE_CHANNEL_LENGTH as i32),
-            LexActivatorError::LA_E_VM => write!(f, "{} Application is running inside virtual machine / hypervisor and activation has been disallowed in the VM.", LexActivatorError::LA_E_VM as i32),
-            LexActivatorError::LA_E_COUNTRY => write!(f, "{} Country is not allowed.", LexActivatorError::LA_E_COUNTRY as i32),
-            LexActivatorError::LA_E_IP => write!(f, "{} IP address is not allowed.", LexActivatorError::LA_E_IP as i32),
-            LexActivatorError::LA_E_CONTAINER => write!(f, "{} Application is being run inside a container and activation has been disallowed in the container.", LexActivatorError::LA_E_CONTAINER as i32),
-            LexActivatorError::LA_E_RELEASE_VERSION => write!(f, "{} Invalid release version. Make sure the release version uses the following formats: x.x, x.x.x, x.x.x.x (where x is a number).", LexActivatorError::LA_E_RELEASE_VERSION as i32),
-            LexActivatorError::LA_E_RELEASE_PLATFORM => write!(f, "{} Release platform not set.", LexActivatorError::LA_E_RELEASE_PLATFORM as i32),
-            LexActivatorError::LA_E_RELEASE_CHANNEL => write!(f, "{} Release channel not set.", LexActivatorError::LA_E_RELEASE_CHANNEL as i32),
-            LexActivatorError::LA_E_USER_NOT_AUTHENTICATED => write!(f, "{} The user is not authenticated.", LexActivatorError::LA_E_USER_NOT_AUTHENTICATED as i32),
-            LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_MISSING => write!(f, "{} The two-factor authentication code for the user authentication is missing.", LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_MISSING as i32),
-            LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_INVALID => write!(f, "{} he two-factor authentication code provided by the user is invalid.", LexActivatorError::LA_E_TWO_FACTOR_AUTHENTICATION_CODE_INVALID as i32),
-            LexActivatorError::LA_E_RATE_LIMIT => write!(f, "{} Rate limit for API has reached, try again later.", LexActivatorError::LA_E_RATE_LIMIT as i32),
-            LexActivatorError::LA_E_SERVER => write!(f, "{} Server error.", LexActivatorError::LA_E_SERVER as i32),
-            LexActivatorError::LA_E_CLIENT => write!(f, "{} Client error.", LexActivatorError::LA_E_CLIENT as i32),
-            LexActivatorError::LA_E_LOGIN_TEMPORARILY_LOCKED => write!(f, "{} The user account has been temporarily locked for 5 mins due to 5 failed attempts.", LexActivatorError::LA_E_LOGIN_TEMPORARILY_LOCKED as i32),
-            LexActivatorError::LA_E_AUTHENTICATION_ID_TOKEN_INVALID => write!(f, "{} Invalid authentication ID token.", LexActivatorError::LA_E_AUTHENTICATION_ID_TOKEN_INVALID as i32),
-            LexActivatorError::LA_E_OIDC_SSO_NOT_ENABLED => write!(f, "{} OIDC SSO is not enabled.", LexActivatorError::LA_E_OIDC_SSO_NOT_ENABLED as i32),
-            LexActivatorError::LA_E_USERS_LIMIT_REACHED => write!(f, "{} The allowed users for this account has reached its limit.", LexActivatorError::LA_E_USERS_LIMIT_REACHED as i32),
-            LexActivatorError::LA_E_OS_USER => write!(f, "{} OS user has changed since activation and the license is user-locked.", LexActivatorError::LA_E_OS_USER as i32),
-            LexActivatorError::LA_E_INVALID_PERMISSION_FLAG => write!(f, "{} Invalid permission flag.", LexActivatorError::LA_E_INVALID_PERMISSION_FLAG as i32),
-            LexActivatorError::LA_E_FREE_PLAN_ACTIVATION_LIMIT_REACHED => write!(f, "{} The free plan has reached its activation limit.", LexActivatorError::LA_E_FREE_PLAN_ACTIVATION_LIMIT_REACHED as i32),
-            LexActivatorError::LA_E_ACCOUNT_ID => write!(f, "{} Invalid account ID.", LexActivatorError::LA_E_ACCOUNT_ID as i32),
-            LexActivatorError::LA_E_FEATURE_ENTITLEMENTS_INVALID => write!(f, "{} Invalid feature entitlements.", LexActivatorError::LA_E_FEATURE_ENTITLEMENTS_INVALID as i32),
-            LexActivatorError::LA_E_FEATURE_ENTITLEMENT_NOT_FOUND => write!(f, "{} The feature entitlement does not exist.", LexActivatorError::LA_E_FEATURE_ENTITLEMENT_NOT_FOUND as i32),
-            LexActivatorError::LA_E_ENTITLEMENT_SET_NOT_LINKED => write!(f, "{} No entitlement set is linked to the license.", LexActivatorError::LA_E_ENTITLEMENT_SET_NOT_LINKED as i32),
-            LexActivatorError::LA_E_LICENSE_NOT_EFFECTIVE => write!(f, "{} The license cannot be activated before its effective date.", LexActivatorError::LA_E_LICENSE_NOT_EFFECTIVE as i32),
+            LexActivatorError::JsonParse(error) => write!(f, "{} {} {}", self.code(), self.message(), error),
+            LexActivatorError::NotImplemented(function) => write!(f, "{} {} ({})", self.code(), self.message(), function),
+            _ => write!(f, "{} {}", self.code(), self.message()),
         }
     }
 }
 
+impl std::error::Error for LexActivatorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LexActivatorError::JsonParse(error) => Some(error.serde_error()),
+            _ => None,
+        }
+    }
+}
+
+/// Details of a JSON document returned by the LexActivator library that could not be parsed.
+#[derive(Debug, Clone)]
+pub struct JsonParseError {
+    raw_json: String,
+    error: Arc<serde_json::Error>,
+}
+
+impl JsonParseError {
+    pub(crate) fn new(raw_json: String, error: serde_json::Error) -> Self {
+        JsonParseError { raw_json, error: Arc::new(error) }
+    }
+
+    /// Returns the JSON exactly as it was returned by the LexActivator library.
+    pub fn raw_json(&self) -> &str {
+        &self.raw_json
+    }
+
+    /// Returns the underlying `serde_json` error, including the line and column of the failure.
+    pub fn serde_error(&self) -> &serde_json::Error {
+        &self.error
+    }
+}
+
+impl PartialEq for JsonParseError {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_json == other.raw_json && self.error.to_string() == other.error.to_string()
+    }
+}
+
+impl fmt::Display for JsonParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
 impl From<NulError> for LexActivatorError {
     fn from(_: NulError) -> Self {
         LexActivatorError::LA_E_CLIENT  
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 #[repr(i32)]
 pub enum LexActivatorCode {
     Status(LexActivatorStatus),
@@ -364,11 +594,89 @@ pub enum LexActivatorCode {
 }
 
 impl LexActivatorCode {
+    /// Converts a code returned by the LexActivator library. Codes below 40 are status codes, all others are error codes.
     pub fn from_i32(code: i32) -> Self {
         match code {
-            0..=32 => LexActivatorCode::Status(LexActivatorStatus::from(code)),
-            40..=110 => LexActivatorCode::Error(LexActivatorError::from(code)),
-            _ => LexActivatorCode::Error(LexActivatorError::LA_E_CLIENT), // Fallback to a general error
+            0..=39 => LexActivatorCode::Status(LexActivatorStatus::from(code)),
+            _ => LexActivatorCode::Error(LexActivatorError::from(code)),
+        }
+    }
+
+    /// Returns the numeric code as returned by the LexActivator library.
+    pub fn code(&self) -> i32 {
+        match self {
+            LexActivatorCode::Status(status) => status.code(),
+            LexActivatorCode::Error(error) => error.code(),
         }
     }
-}
\ No newline at end of file
+}
+/// With the `serde` feature, status and error codes are serialized as their numeric code and message,
+/// e.g. `{"code":48,"message":"Failed to connect to the server due to network error."}`. Only the code is
+/// read when deserializing.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+    use serde::ser::SerializeStruct;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    fn serialize_code<S: Serializer>(serializer: S, name: &'static str, code: i32, message: &str) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct(name, 2)?;
+        state.serialize_field("code", &code)?;
+        state.serialize_field("message", message)?;
+        state.end()
+    }
+
+    #[derive(Deserialize)]
+    struct SerializedCode {
+        code: i32,
+    }
+
+    impl Serialize for LexActivatorStatus {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_code(serializer, "LexActivatorStatus", self.code(), self.message())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LexActivatorStatus {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            SerializedCode::deserialize(deserializer).map(|serialized| LexActivatorStatus::from(serialized.code))
+        }
+    }
+
+    impl Serialize for LexActivatorError {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                LexActivatorError::JsonParse(error) => {
+                    let message = format!("{} {}", self.message(), error);
+                    serialize_code(serializer, "LexActivatorError", self.code(), &message)
+                }
+                LexActivatorError::NotImplemented(function) => {
+                    let message = format!("{} ({})", self.message(), function);
+                    serialize_code(serializer, "LexActivatorError", self.code(), &message)
+                }
+                _ => serialize_code(serializer, "LexActivatorError", self.code(), self.message()),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LexActivatorError {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            SerializedCode::deserialize(deserializer).map(|serialized| LexActivatorError::from(serialized.code))
+        }
+    }
+
+    impl Serialize for LexActivatorCode {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                LexActivatorCode::Status(status) => status.serialize(serializer),
+                LexActivatorCode::Error(error) => error.serialize(serializer),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LexActivatorCode {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            SerializedCode::deserialize(deserializer).map(|serialized| LexActivatorCode::from_i32(serialized.code))
+        }
+    }
+}
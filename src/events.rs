@@ -0,0 +1,259 @@
+//! Multiple subscribers for the license callback.
+//!
+//! The LexActivator library supports a single license callback. [`subscribe_license_callback`] and
+//! [`subscribe_license_channel`] let any number of listeners receive every [`LexActivatorCode`] delivered
+//! to it. Each subscription stays active until the returned [`LicenseCallbackSubscription`] is dropped.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! let _telemetry = subscribe_license_callback(|code| println!("License callback: {:?}", code))?;
+//!
+//! let (sender, receiver) = std::sync::mpsc::channel();
+//! let _ui = subscribe_license_channel(sender)?;
+//!
+//! let _gate = subscribe_license_events(|event| match event {
+//!     LicenseEvent::Valid => println!("License is valid"),
+//!     LicenseEvent::GracePeriodOver => println!("License must be synced with the server"),
+//!     LicenseEvent::Expired | LicenseEvent::Suspended | LicenseEvent::Revoked => println!("License is no longer usable"),
+//!     LicenseEvent::ActivationDeleted | LicenseEvent::FingerprintChanged => println!("Activation is no longer valid"),
+//!     LicenseEvent::TransientNetworkFailure(error) => println!("Server sync failed: {}", error),
+//!     LicenseEvent::Other(code) => println!("License callback: {:?}", code),
+//! })?;
+//! ```
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+
+use crate::*;
+
+type Subscriber = Arc<dyn Fn(LexActivatorCode) + Send + Sync + 'static>;
+
+type PanicHandler = Arc<dyn Fn(String) + Send + Sync + 'static>;
+
+static SUBSCRIBERS: LazyLock<Mutex<Vec<(u64, Subscriber)>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+static PANIC_HANDLER: LazyLock<Mutex<Option<PanicHandler>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Delivers a code received by the license callback to every subscriber.
+///
+/// The subscribers are cloned out of the list first, so they can subscribe and unsubscribe from inside the callback.
+pub(crate) fn dispatch(code: &LexActivatorCode) {
+    let subscribers: Vec<Subscriber> = SUBSCRIBERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .map(|(_, subscriber)| subscriber.clone())
+        .collect();
+    for subscriber in subscribers {
+        call_guarded(|| subscriber(code.clone()));
+    }
+}
+
+/// Runs a license callback, catching a panic so that it does not unwind into the LexActivator library.
+/// The panic is reported to the handler set by [`set_license_callback_panic_handler`].
+pub(crate) fn call_guarded<F: FnOnce()>(callback: F) {
+    let Err(payload) = panic::catch_unwind(AssertUnwindSafe(callback)) else {
+        return;
+    };
+    let message = panic_message(payload.as_ref());
+    let handler = PANIC_HANDLER.lock().unwrap_or_else(PoisonError::into_inner).clone();
+    if let Some(handler) = handler {
+        // A panicking panic handler is not reported again.
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| handler(message)));
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Sets the handler that is called with the panic message when a license callback or subscriber panics.
+///
+/// Panics in license callbacks are caught so that they do not unwind into the LexActivator library. Besides
+/// the default panic hook, which prints the panic to stderr, they are only reported to this handler.
+///
+/// # Arguments
+///
+/// * `handler` - The closure to be called with the panic message e.g. |message| { log::error!("{}", message) }
+pub fn set_license_callback_panic_handler<F>(handler: F)
+where
+    F: Fn(String) + Send + Sync + 'static,
+{
+    PANIC_HANDLER.lock().unwrap_or_else(PoisonError::into_inner).replace(Arc::new(handler));
+}
+
+/// Returns the number of subscribers of the license callback, i.e. the subscriptions that have not been dropped.
+pub fn license_callback_subscriber_count() -> usize {
+    SUBSCRIBERS.lock().unwrap_or_else(PoisonError::into_inner).len()
+}
+
+/// A subscription to the license callback. The subscriber is removed when the subscription is dropped.
+#[derive(Debug)]
+#[must_use = "the subscriber is removed when the subscription is dropped"]
+pub struct LicenseCallbackSubscription {
+    id: u64,
+}
+
+impl Drop for LicenseCallbackSubscription {
+    fn drop(&mut self) {
+        let mut subscribers = SUBSCRIBERS.lock().unwrap_or_else(PoisonError::into_inner);
+        subscribers.retain(|(id, _)| *id != self.id);
+    }
+}
+
+/// Sends the codes received by a channel subscriber, see [`subscribe_license_channel`].
+///
+/// Implementations must not block, as they are called on the thread of the LexActivator library.
+pub trait LicenseCodeSender: Send + Sync + 'static {
+    /// Sends the code. Codes that cannot be delivered, e.g. because the receiver was dropped, are discarded.
+    fn send_code(&self, code: LexActivatorCode);
+}
+
+impl LicenseCodeSender for std::sync::mpsc::Sender<LexActivatorCode> {
+    fn send_code(&self, code: LexActivatorCode) {
+        let _ = self.send(code);
+    }
+}
+
+impl LicenseCodeSender for std::sync::mpsc::SyncSender<LexActivatorCode> {
+    fn send_code(&self, code: LexActivatorCode) {
+        let _ = self.try_send(code);
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl LicenseCodeSender for crossbeam_channel::Sender<LexActivatorCode> {
+    fn send_code(&self, code: LexActivatorCode) {
+        let _ = self.try_send(code);
+    }
+}
+
+/// Subscribes a closure to the license callback.
+///
+/// Unlike [`set_license_callback`], which replaces the previous callback, any number of closures can be
+/// subscribed. Each of them receives every code, including the closure set by [`set_license_callback`].
+///
+/// # Arguments
+///
+/// * `closure` - The closure to be called with each code e.g. |code| { println!("{:?}", code) }
+///
+/// # Returns
+///
+/// Returns `Ok(LicenseCallbackSubscription)` if the closure is subscribed successfully, If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn subscribe_license_callback<F>(closure: F) -> Result<LicenseCallbackSubscription, LexActivatorError>
+where
+    F: Fn(LexActivatorCode) + Send + Sync + 'static,
+{
+    let status: i32 = unsafe { SetLicenseCallback(wrapper) };
+    if status != 0 {
+        return Err(LexActivatorError::from(status));
+    }
+    let id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed);
+    SUBSCRIBERS.lock().unwrap_or_else(PoisonError::into_inner).push((id, Arc::new(closure)));
+    Ok(LicenseCallbackSubscription { id })
+}
+
+/// Subscribes a channel to the license callback. Every code is sent to the channel.
+///
+/// `std::sync::mpsc` senders are supported, as are `crossbeam_channel` senders with the `crossbeam` feature.
+/// Bounded channels drop the codes that do not fit.
+///
+/// # Arguments
+///
+/// * `sender` - The sending half of the channel.
+///
+/// # Returns
+///
+/// Returns `Ok(LicenseCallbackSubscription)` if the channel is subscribed successfully, If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn subscribe_license_channel<S: LicenseCodeSender>(sender: S) -> Result<LicenseCallbackSubscription, LexActivatorError> {
+    subscribe_license_callback(move |code| sender.send_code(code))
+}
+
+/// The result of a background server sync, as delivered to the license callback.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LicenseEvent {
+    /// The license is valid (`LA_OK`).
+    Valid,
+    /// The server sync grace period is over (`LA_GRACE_PERIOD_OVER`).
+    GracePeriodOver,
+    /// The license has expired (`LA_EXPIRED`).
+    Expired,
+    /// The license has been suspended (`LA_SUSPENDED`).
+    Suspended,
+    /// The license has been revoked (`LA_E_REVOKED`).
+    Revoked,
+    /// The activation was deleted on the server (`LA_E_ACTIVATION_NOT_FOUND`).
+    ActivationDeleted,
+    /// The machine fingerprint has changed since the activation (`LA_E_MACHINE_FINGERPRINT`).
+    FingerprintChanged,
+    /// The server could not be reached or did not respond (`LA_E_INET`, `LA_E_SERVER` or `LA_E_RATE_LIMIT`).
+    /// The license keeps its previous state until the next successful sync.
+    TransientNetworkFailure(LexActivatorError),
+    /// Any other code.
+    Other(LexActivatorCode),
+}
+
+impl From<LexActivatorCode> for LicenseEvent {
+    fn from(code: LexActivatorCode) -> Self {
+        match code {
+            LexActivatorCode::Status(LexActivatorStatus::LA_OK) => LicenseEvent::Valid,
+            LexActivatorCode::Status(LexActivatorStatus::LA_GRACE_PERIOD_OVER) => LicenseEvent::GracePeriodOver,
+            LexActivatorCode::Status(LexActivatorStatus::LA_EXPIRED) => LicenseEvent::Expired,
+            LexActivatorCode::Status(LexActivatorStatus::LA_SUSPENDED) => LicenseEvent::Suspended,
+            LexActivatorCode::Error(LexActivatorError::LA_E_REVOKED) => LicenseEvent::Revoked,
+            LexActivatorCode::Error(LexActivatorError::LA_E_ACTIVATION_NOT_FOUND) => LicenseEvent::ActivationDeleted,
+            LexActivatorCode::Error(LexActivatorError::LA_E_MACHINE_FINGERPRINT) => LicenseEvent::FingerprintChanged,
+            LexActivatorCode::Error(error @ (LexActivatorError::LA_E_INET | LexActivatorError::LA_E_SERVER | LexActivatorError::LA_E_RATE_LIMIT)) => {
+                LicenseEvent::TransientNetworkFailure(error)
+            }
+            code => LicenseEvent::Other(code),
+        }
+    }
+}
+
+impl LicenseEvent {
+    /// Returns the numeric code as returned by the LexActivator library.
+    pub fn code(&self) -> i32 {
+        match self {
+            LicenseEvent::Valid => LexActivatorStatus::LA_OK.code(),
+            LicenseEvent::GracePeriodOver => LexActivatorStatus::LA_GRACE_PERIOD_OVER.code(),
+            LicenseEvent::Expired => LexActivatorStatus::LA_EXPIRED.code(),
+            LicenseEvent::Suspended => LexActivatorStatus::LA_SUSPENDED.code(),
+            LicenseEvent::Revoked => LexActivatorError::LA_E_REVOKED.code(),
+            LicenseEvent::ActivationDeleted => LexActivatorError::LA_E_ACTIVATION_NOT_FOUND.code(),
+            LicenseEvent::FingerprintChanged => LexActivatorError::LA_E_MACHINE_FINGERPRINT.code(),
+            LicenseEvent::TransientNetworkFailure(error) => error.code(),
+            LicenseEvent::Other(code) => code.code(),
+        }
+    }
+}
+
+/// Subscribes a closure to the license callback that receives each code as a [`LicenseEvent`].
+///
+/// # Arguments
+///
+/// * `closure` - The closure to be called with each event e.g. |event| { println!("{:?}", event) }
+///
+/// # Returns
+///
+/// Returns `Ok(LicenseCallbackSubscription)` if the closure is subscribed successfully, If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn subscribe_license_events<F>(closure: F) -> Result<LicenseCallbackSubscription, LexActivatorError>
+where
+    F: Fn(LicenseEvent) + Send + Sync + 'static,
+{
+    subscribe_license_callback(move |code| closure(LicenseEvent::from(code)))
+}
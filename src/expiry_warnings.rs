@@ -0,0 +1,316 @@
+//! Warnings ahead of license, maintenance, trial and grace period expiry.
+//!
+//! [`ExpiryWarnings`] compares the expiry dates of the license with warning thresholds, e.g. 30, 7 and 1 days,
+//! and returns an [`ExpiryWarning`] once for every threshold that is crossed. The delivered warnings are
+//! stored in a file in the data directory, so they are not repeated after a restart. When an expiry date
+//! changes, e.g. because the license was renewed, its warnings are delivered again.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+//!
+//! let _scheduler = ExpiryWarnings::builder("/var/lib/my-app")
+//!     .thresholds([30 * DAY, 7 * DAY, DAY])
+//!     .open()?
+//!     .schedule(
+//!         Duration::from_secs(60 * 60),
+//!         |warning| println!("{} in {} days", warning.deadline, warning.time_remaining.as_secs() / DAY.as_secs()),
+//!         |error| log::error!("Failed to store the expiry warnings: {}", error),
+//!     )?;
+//! ```
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::periodic::PeriodicThread;
+use crate::*;
+
+/// Name of the file in the data directory that stores the delivered warnings.
+pub const EXPIRY_WARNINGS_FILE_NAME: &str = "lexactivator-expiry-warnings.json";
+
+/// An expiry date that warnings are delivered for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpiryDeadline {
+    /// See [`get_license_expiry_date`].
+    LicenseExpiry,
+    /// See [`get_license_maintenance_expiry_date`].
+    MaintenanceExpiry,
+    /// See [`get_trial_expiry_date`].
+    TrialExpiry,
+    /// See [`get_server_sync_grace_period_expiry_date`].
+    ServerSyncGracePeriodExpiry,
+}
+
+impl ExpiryDeadline {
+    /// All deadlines, the default of [`ExpiryWarningsBuilder`].
+    pub const ALL: [ExpiryDeadline; 4] = [
+        ExpiryDeadline::LicenseExpiry,
+        ExpiryDeadline::MaintenanceExpiry,
+        ExpiryDeadline::TrialExpiry,
+        ExpiryDeadline::ServerSyncGracePeriodExpiry,
+    ];
+
+    fn expiry_time<B: LicensingBackend>(&self, backend: &B) -> Result<Option<SystemTime>, LexActivatorError> {
+        match self {
+            ExpiryDeadline::LicenseExpiry => backend.get_license_expiry_time(),
+            ExpiryDeadline::MaintenanceExpiry => backend.get_license_maintenance_expiry_time(),
+            ExpiryDeadline::TrialExpiry => backend.get_trial_expiry_time(),
+            ExpiryDeadline::ServerSyncGracePeriodExpiry => backend.get_server_sync_grace_period_expiry_time(),
+        }
+    }
+}
+
+impl fmt::Display for ExpiryDeadline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpiryDeadline::LicenseExpiry => write!(f, "License expiry"),
+            ExpiryDeadline::MaintenanceExpiry => write!(f, "Maintenance expiry"),
+            ExpiryDeadline::TrialExpiry => write!(f, "Trial expiry"),
+            ExpiryDeadline::ServerSyncGracePeriodExpiry => write!(f, "Server sync grace period expiry"),
+        }
+    }
+}
+
+/// A warning that an expiry date is closer than a threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiryWarning {
+    /// The expiry date the warning is about.
+    pub deadline: ExpiryDeadline,
+    /// The threshold that was crossed.
+    pub threshold: Duration,
+    /// The expiry date.
+    pub expires_at: SystemTime,
+    /// The time remaining until the expiry date at the time of the check.
+    pub time_remaining: Duration,
+}
+
+/// Error returned by [`ExpiryWarningsBuilder::open`].
+#[derive(Debug)]
+pub enum ExpiryWarningsError {
+    /// No warning threshold was added to the builder, so no warning would ever be delivered.
+    NoThresholds,
+    /// The delivered warnings could not be read.
+    StateFile(StateFileError),
+}
+
+impl fmt::Display for ExpiryWarningsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpiryWarningsError::NoThresholds => write!(f, "No expiry warning thresholds are set"),
+            ExpiryWarningsError::StateFile(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ExpiryWarningsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExpiryWarningsError::NoThresholds => None,
+            ExpiryWarningsError::StateFile(error) => Some(error),
+        }
+    }
+}
+
+impl From<StateFileError> for ExpiryWarningsError {
+    fn from(error: StateFileError) -> Self {
+        ExpiryWarningsError::StateFile(error)
+    }
+}
+
+/// A delivered warning, as stored in the data directory.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Delivered {
+    deadline: ExpiryDeadline,
+    /// The expiry date in seconds since the Unix epoch, so that a changed date is warned about again.
+    expires_at: u64,
+    threshold_secs: u64,
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// Builder for [`ExpiryWarnings`], created by [`ExpiryWarnings::builder`].
+#[derive(Debug, Clone)]
+pub struct ExpiryWarningsBuilder {
+    data_directory: PathBuf,
+    thresholds: Vec<Duration>,
+    deadlines: Vec<ExpiryDeadline>,
+}
+
+impl ExpiryWarningsBuilder {
+    /// Adds a warning threshold, i.e. how long before an expiry date its warning is delivered.
+    pub fn threshold(mut self, threshold: Duration) -> Self {
+        self.thresholds.push(threshold);
+        self
+    }
+
+    /// Adds several warning thresholds.
+    pub fn thresholds(mut self, thresholds: impl IntoIterator<Item = Duration>) -> Self {
+        self.thresholds.extend(thresholds);
+        self
+    }
+
+    /// Sets the expiry dates to warn about. Defaults to [`ExpiryDeadline::ALL`].
+    pub fn deadlines(mut self, deadlines: impl IntoIterator<Item = ExpiryDeadline>) -> Self {
+        self.deadlines = deadlines.into_iter().collect();
+        self
+    }
+
+    /// Reads the delivered warnings from the data directory.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(ExpiryWarnings)` if the delivered warnings are read successfully, If no threshold is set or an error occurs, an `Err` containing the `ExpiryWarningsError` is returned.
+    pub fn open(self) -> Result<ExpiryWarnings, ExpiryWarningsError> {
+        self.open_with(LexActivator::new())
+    }
+
+    /// Same as [`ExpiryWarningsBuilder::open`], reading the expiry dates through the given handle.
+    pub fn open_with<B: LicensingBackend>(mut self, lexactivator: LexActivator<B>) -> Result<ExpiryWarnings<B>, ExpiryWarningsError> {
+        if self.thresholds.is_empty() {
+            return Err(ExpiryWarningsError::NoThresholds);
+        }
+        let path = self.data_directory.join(EXPIRY_WARNINGS_FILE_NAME);
+        let delivered = state_file::read(&path)?.unwrap_or_default();
+        // Largest threshold first, so that the last crossed threshold is the closest to the expiry date.
+        self.thresholds.sort_unstable_by(|a, b| b.cmp(a));
+        self.thresholds.dedup();
+        Ok(ExpiryWarnings {
+            lexactivator,
+            path,
+            thresholds: self.thresholds,
+            deadlines: self.deadlines,
+            delivered: Mutex::new(delivered),
+        })
+    }
+}
+
+/// Warning thresholds and the warnings delivered so far.
+#[derive(Debug)]
+pub struct ExpiryWarnings<B: LicensingBackend = NativeBackend> {
+    lexactivator: LexActivator<B>,
+    path: PathBuf,
+    thresholds: Vec<Duration>,
+    deadlines: Vec<ExpiryDeadline>,
+    delivered: Mutex<BTreeSet<Delivered>>,
+}
+
+impl ExpiryWarnings {
+    /// Returns a builder that stores the delivered warnings in the given data directory, usually the directory
+    /// passed to [`set_data_directory`].
+    pub fn builder(data_directory: impl AsRef<Path>) -> ExpiryWarningsBuilder {
+        ExpiryWarningsBuilder {
+            data_directory: data_directory.as_ref().to_path_buf(),
+            thresholds: Vec::new(),
+            deadlines: ExpiryDeadline::ALL.to_vec(),
+        }
+    }
+}
+
+impl<B: LicensingBackend> ExpiryWarnings<B> {
+    /// Reads the expiry dates and returns the warnings for the thresholds crossed since the last check.
+    ///
+    /// If several thresholds of an expiry date were crossed, only the warning for the closest one is returned
+    /// and the others are marked as delivered. Expiry dates that are not set or cannot be read, e.g. the trial
+    /// expiry date without a trial, and expiry dates that have passed are skipped. The delivered warnings of an
+    /// expiry date that cannot be read are kept.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vec<ExpiryWarning>)` with the new warnings, which are stored as delivered. If they cannot be stored, an `Err` containing the `StateFileError` is returned and the warnings are returned again by the next check.
+    pub fn check(&self) -> Result<Vec<ExpiryWarning>, StateFileError> {
+        let now = SystemTime::now();
+        let mut delivered = self.delivered.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut updated = BTreeSet::new();
+        let mut warnings = Vec::new();
+        for deadline in &self.deadlines {
+            let expires_at = match deadline.expiry_time(self.lexactivator.backend()) {
+                Ok(Some(expires_at)) => expires_at,
+                Ok(None) => continue,
+                // The delivered warnings are kept, so that they are not repeated once the date can be read again.
+                Err(_) => {
+                    updated.extend(delivered.iter().filter(|entry| entry.deadline == *deadline).cloned());
+                    continue;
+                }
+            };
+            let Ok(time_remaining) = expires_at.duration_since(now) else {
+                continue;
+            };
+            let mut warning = None;
+            for threshold in self.thresholds.iter().filter(|threshold| time_remaining <= **threshold) {
+                let entry = Delivered { deadline: *deadline, expires_at: unix_seconds(expires_at), threshold_secs: threshold.as_secs() };
+                if !delivered.contains(&entry) {
+                    warning = Some(ExpiryWarning { deadline: *deadline, threshold: *threshold, expires_at, time_remaining });
+                }
+                updated.insert(entry);
+            }
+            warnings.extend(warning);
+        }
+        // Warnings for expiry dates that changed or passed are no longer needed.
+        if updated != *delivered {
+            state_file::write(&self.path, &updated)?;
+            *delivered = updated;
+        }
+        Ok(warnings)
+    }
+}
+
+impl<B: LicensingBackend + Send + Sync + 'static> ExpiryWarnings<B> {
+    /// Checks the expiry dates now and then on a background thread after every interval, and calls the
+    /// closure with every new warning.
+    ///
+    /// Checks that fail to store the delivered warnings are passed to `on_error` and retried after the next
+    /// interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The time between two checks, at least one second.
+    /// * `on_warning` - The closure to be called with each warning e.g. |warning| { println!("{:?}", warning) }
+    /// * `on_error` - The closure to be called with each failed check e.g. |error| { eprintln!("{}", error) }
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(ExpiryWarningScheduler)` if the thread is started successfully. If the thread cannot be spawned, an `Err` containing the `std::io::Error` is returned.
+    pub fn schedule<F, E>(self, interval: Duration, on_warning: F, on_error: E) -> io::Result<ExpiryWarningScheduler>
+    where
+        F: Fn(&ExpiryWarning) + Send + Sync + 'static,
+        E: Fn(StateFileError) + Send + Sync + 'static,
+    {
+        let warnings = Arc::new(self);
+        let check = move || match warnings.check() {
+            Ok(warnings) => {
+                for warning in warnings {
+                    events::call_guarded(|| on_warning(&warning));
+                }
+            }
+            Err(error) => events::call_guarded(|| on_error(error)),
+        };
+        check();
+        let thread = PeriodicThread::spawn("lexactivator-expiry-warnings", interval, check)?;
+        Ok(ExpiryWarningScheduler { thread })
+    }
+}
+
+/// Checks for expiry warnings on a background thread, created by [`ExpiryWarnings::schedule`].
+///
+/// Dropping the scheduler stops it and waits for its thread to finish.
+#[derive(Debug)]
+pub struct ExpiryWarningScheduler {
+    thread: PeriodicThread,
+}
+
+impl ExpiryWarningScheduler {
+    /// Stops the scheduler and waits for a running check to finish.
+    pub fn stop(mut self) {
+        self.thread.stop();
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "stub", allow(unused))] // the extern block is replaced by the stub implementation
+
 //https://doc.rust-lang.org/std/os/raw/index.html#types
 use std::ffi::{c_char, c_int, c_uint, c_longlong, c_ulonglong};
 
@@ -33,6 +35,11 @@ macro_rules! strtype {
 
 pub type CallbackType = extern "C" fn(i32);
 
+// With the stub feature the same symbols are provided by the in-crate implementation
+#[cfg(feature = "stub")]
+pub(crate) use crate::stub::*;
+
+#[cfg(not(feature = "stub"))]
 extern "C" {
     // --------------- Setter Functions ---------------
     pub fn SetProductData(productData: cstrtype!()) -> c_int;
@@ -0,0 +1,227 @@
+//! Feature checks answered from memory.
+//!
+//! [`FeatureGate`] loads the feature entitlements of the license once and answers [`FeatureGate::is_enabled`]
+//! and [`FeatureGate::limit`] without calling the LexActivator library. Features that are missing from the
+//! entitlement set, or whose entitlement has expired, fall back to the defaults declared on the
+//! [`FeatureGateBuilder`]. Before the license is activated only the defaults apply.
+//!
+//! The entitlements are reloaded on a separate thread whenever the license callback reports a server sync.
+//! When it reports that the license has expired, been suspended or revoked, or that the activation was
+//! deleted, the entitlements are dropped and only the defaults apply until the next successful refresh.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! let features = FeatureGate::builder()
+//!     .default_enabled("export", false)
+//!     .default_limit("max-projects", 3)
+//!     .build()?;
+//!
+//! if features.is_enabled("export") {
+//!     // ...
+//! }
+//! let max_projects = features.limit("max-projects").unwrap_or(0);
+//! ```
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::*;
+
+/// Builder for a [`FeatureGate`].
+#[derive(Debug, Clone)]
+pub struct FeatureGateBuilder {
+    defaults: HashMap<String, FeatureEntitlement>,
+    auto_refresh: bool,
+}
+
+impl Default for FeatureGateBuilder {
+    fn default() -> Self {
+        FeatureGateBuilder { defaults: HashMap::new(), auto_refresh: true }
+    }
+}
+
+impl FeatureGateBuilder {
+    /// Creates a builder without defaults that refreshes the entitlements on server syncs.
+    pub fn new() -> Self {
+        FeatureGateBuilder::default()
+    }
+
+    /// Declares the value of a feature that is missing from the entitlement set. The value is parsed like
+    /// the value of an entitlement.
+    pub fn default_value(mut self, feature_name: impl Into<String>, value: impl Into<String>) -> Self {
+        let feature_name = feature_name.into();
+        let value = value.into();
+        let entitlement = FeatureEntitlement {
+            feature_name: feature_name.clone(),
+            feature_display_name: String::new(),
+            value: value.clone(),
+            baseValue: value,
+            expires_at: 0,
+        };
+        self.defaults.insert(feature_name, entitlement);
+        self
+    }
+
+    /// Declares whether a feature that is missing from the entitlement set is enabled.
+    pub fn default_enabled(self, feature_name: impl Into<String>, enabled: bool) -> Self {
+        self.default_value(feature_name, enabled.to_string())
+    }
+
+    /// Declares the limit of a feature that is missing from the entitlement set.
+    pub fn default_limit(self, feature_name: impl Into<String>, limit: i64) -> Self {
+        self.default_value(feature_name, limit.to_string())
+    }
+
+    /// Sets whether the entitlements are reloaded or dropped when the license callback reports a server sync.
+    /// Enabled by default.
+    pub fn auto_refresh(mut self, auto_refresh: bool) -> Self {
+        self.auto_refresh = auto_refresh;
+        self
+    }
+
+    /// Loads the feature entitlements from the LexActivator library.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(FeatureGate)` if the entitlements are loaded successfully, or if the license is not activated
+    /// yet, in which case only the defaults apply. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+    pub fn build(self) -> Result<FeatureGate, LexActivatorError> {
+        self.build_with(LexActivator::new())
+    }
+
+    /// Same as [`FeatureGateBuilder::build`], loading the feature entitlements from the given handle.
+    pub fn build_with<B>(self, lexactivator: LexActivator<B>) -> Result<FeatureGate<B>, LexActivatorError>
+    where
+        B: LicensingBackend + Send + Sync + 'static,
+    {
+        let shared = Arc::new(Shared {
+            lexactivator,
+            defaults: self.defaults,
+            entitlements: RwLock::new(HashMap::new()),
+            generation: AtomicU64::new(0),
+        });
+        shared.refresh()?;
+        let subscription = if self.auto_refresh {
+            let weak = Arc::downgrade(&shared);
+            Some(subscribe_license_events(move |event| {
+                let Some(shared) = weak.upgrade() else {
+                    return;
+                };
+                match event {
+                    // A failed sync does not change the entitlements.
+                    LicenseEvent::TransientNetworkFailure(_) => {}
+                    LicenseEvent::Expired | LicenseEvent::Suspended | LicenseEvent::Revoked | LicenseEvent::ActivationDeleted => {
+                        shared.clear();
+                    }
+                    _ => {
+                        // The LexActivator library is not called from its own callback thread. On failure the
+                        // previously loaded entitlements are kept.
+                        std::thread::spawn(move || {
+                            let _ = shared.refresh();
+                        });
+                    }
+                }
+            })?)
+        } else {
+            None
+        };
+        Ok(FeatureGate { shared, _subscription: subscription })
+    }
+}
+
+#[derive(Debug)]
+struct Shared<B: LicensingBackend> {
+    lexactivator: LexActivator<B>,
+    defaults: HashMap<String, FeatureEntitlement>,
+    entitlements: RwLock<HashMap<String, FeatureEntitlement>>,
+    // Incremented when the entitlements are dropped, so that a refresh that started before does not restore them.
+    generation: AtomicU64,
+}
+
+impl<B: LicensingBackend> Shared<B> {
+    fn refresh(&self) -> Result<(), LexActivatorError> {
+        let generation = self.generation.load(Ordering::SeqCst);
+        let entitlements = match self.lexactivator.get_feature_entitlements() {
+            Ok(entitlements) => entitlements,
+            // LA_FAIL means that the license is not activated.
+            Err(LexActivatorError::LA_E_ENTITLEMENT_SET_NOT_LINKED | LexActivatorError::LA_FAIL) => Vec::new(),
+            Err(error) => return Err(error),
+        };
+        let entitlements = entitlements
+            .into_iter()
+            .map(|entitlement| (entitlement.feature_name.clone(), entitlement))
+            .collect();
+        let mut current = self.entitlements.write().unwrap_or_else(PoisonError::into_inner);
+        if self.generation.load(Ordering::SeqCst) == generation {
+            *current = entitlements;
+        }
+        Ok(())
+    }
+
+    fn clear(&self) {
+        let mut current = self.entitlements.write().unwrap_or_else(PoisonError::into_inner);
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        current.clear();
+    }
+
+    fn with_entitlement<T>(&self, feature_name: &str, f: impl FnOnce(&FeatureEntitlement) -> T) -> Option<T> {
+        let entitlements = self.entitlements.read().unwrap_or_else(PoisonError::into_inner);
+        entitlements
+            .get(feature_name)
+            .filter(|entitlement| !entitlement.is_expired())
+            .or_else(|| self.defaults.get(feature_name))
+            .map(f)
+    }
+}
+
+/// Registry of the feature entitlements of the license, created by [`FeatureGate::builder`].
+///
+/// Dropping the gate unsubscribes it from the license callback.
+#[derive(Debug)]
+pub struct FeatureGate<B: LicensingBackend = NativeBackend> {
+    shared: Arc<Shared<B>>,
+    _subscription: Option<LicenseCallbackSubscription>,
+}
+
+impl FeatureGate {
+    /// Returns a builder to declare the feature defaults.
+    pub fn builder() -> FeatureGateBuilder {
+        FeatureGateBuilder::new()
+    }
+}
+
+impl<B: LicensingBackend> FeatureGate<B> {
+    /// Returns `true` if the value of the feature is `true`.
+    ///
+    /// Features that are neither entitled nor declared, and features whose value is not a boolean, are disabled.
+    pub fn is_enabled(&self, feature_name: &str) -> bool {
+        self.shared
+            .with_entitlement(feature_name, |entitlement| entitlement.value_as_bool().unwrap_or(false))
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the feature as an integer, or `None` if the feature is neither entitled nor
+    /// declared, or its value is not an integer.
+    pub fn limit(&self, feature_name: &str) -> Option<i64> {
+        self.shared
+            .with_entitlement(feature_name, |entitlement| entitlement.value_as_i64().ok())
+            .flatten()
+    }
+
+    /// Returns the entitlement of the feature, or the declared default if the feature is missing from the
+    /// entitlement set or its entitlement has expired.
+    pub fn entitlement(&self, feature_name: &str) -> Option<FeatureEntitlement> {
+        self.shared.with_entitlement(feature_name, FeatureEntitlement::clone)
+    }
+
+    /// Reloads the feature entitlements from the LexActivator library.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the entitlements are reloaded successfully, If an error occurs, an `Err` containing the `LexActivatorError` is returned and the previous entitlements are kept.
+    pub fn refresh(&self) -> Result<(), LexActivatorError> {
+        self.shared.refresh()
+    }
+}
@@ -1,6 +1,9 @@
 use std::ffi::*;
+use std::fmt;
+use std::str::FromStr;
 use serde::Deserialize;
-use std::sync::{LazyLock, Mutex};
+use std::sync::{LazyLock, Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
 
 mod extern_functions;
 use extern_functions::*;
@@ -11,21 +14,95 @@ pub use error_codes::*;
 mod string_utils;
 use string_utils::*;
 
-type LicenseCallback = dyn Fn(LexActivatorCode) + Send + 'static;
+mod time_utils;
+use time_utils::*;
 
-static CALLBACK_FUNCTION: LazyLock<Mutex<Option<Box<LicenseCallback>>>> =
+mod periodic;
+
+pub mod state_file;
+pub use state_file::*;
+
+#[cfg(feature = "stub")]
+pub mod stub;
+
+#[cfg(feature = "tokio")]
+pub mod nonblocking;
+
+pub mod backend;
+pub use backend::*;
+
+pub mod events;
+pub use events::*;
+
+pub mod config;
+pub use config::*;
+
+pub mod config_loader;
+pub use config_loader::*;
+
+pub mod snapshot;
+pub use snapshot::*;
+
+pub mod entitlements;
+pub use entitlements::*;
+
+pub mod feature_gate;
+pub use feature_gate::*;
+
+pub mod meter_queue;
+pub use meter_queue::*;
+
+pub mod meter_usage;
+pub use meter_usage::*;
+
+pub mod meter_quota;
+pub use meter_quota::*;
+
+pub mod watchdog;
+pub use watchdog::*;
+
+pub mod expiry_warnings;
+pub use expiry_warnings::*;
+
+/// Serialises the composite operations of this crate that make several calls to the LexActivator library, such
+/// as reading a license snapshot, with each other and with the calls of the async functions, so that they do not
+/// observe e.g. a half-finished activation. Single calls of the plain functions do not take it.
+pub(crate) static API_LOCK: Mutex<()> = Mutex::new(());
+
+/// The closure set by `set_license_callback`. It is cloned out of `CALLBACK_FUNCTION` before it is
+/// called, so that it can replace or unset itself.
+trait LicenseCallback: Fn(LexActivatorCode) + Send + 'static {
+    fn clone_box(&self) -> Box<dyn LicenseCallback>;
+}
+
+impl<F> LicenseCallback for F
+where
+    F: Fn(LexActivatorCode) + Clone + Send + 'static,
+{
+    fn clone_box(&self) -> Box<dyn LicenseCallback> {
+        Box::new(self.clone())
+    }
+}
+
+static CALLBACK_FUNCTION: LazyLock<Mutex<Option<Box<dyn LicenseCallback>>>> =
     LazyLock::new(|| Mutex::new(None));
 
 extern "C" fn wrapper(code: i32) {
     let callback_status = LexActivatorCode::from_i32(code);
-    let callback = CALLBACK_FUNCTION.lock().unwrap();
-    if let Some(callback) = callback.as_ref() {
-        callback(callback_status);
+    events::dispatch(&callback_status);
+    let callback = CALLBACK_FUNCTION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .map(|callback| callback.clone_box());
+    if let Some(callback) = callback {
+        events::call_guarded(|| callback(callback_status));
     }
 }
 
 /// Represents a license meter attribute.
-#[derive(Debug)] 
+#[derive(Debug, Clone)] 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LicenseMeterAttribute {
     /// The name of the meter attribute.
     pub name: String,
@@ -39,6 +116,7 @@ pub struct LicenseMeterAttribute {
 
 /// Represents a product version feature flag.
 #[derive(Debug)] 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProductVersionFeatureFlag {
     /// The name of the feature flag.
     pub name: String,
@@ -49,7 +127,8 @@ pub struct ProductVersionFeatureFlag {
 }
 
 /// Represents an activation mode.
-#[derive(Debug)] 
+#[derive(Debug, Clone)] 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ActivationMode {
     /// The initial activation mode.
     pub initial_mode: String,
@@ -57,8 +136,153 @@ pub struct ActivationMode {
     pub current_mode: String
 }
 
+impl ActivationMode {
+    /// Returns the initial activation mode as an [`ActivationModeKind`].
+    pub fn initial(&self) -> ActivationModeKind {
+        ActivationModeKind::from(self.initial_mode.as_str())
+    }
+
+    /// Returns the current activation mode as an [`ActivationModeKind`].
+    pub fn current(&self) -> ActivationModeKind {
+        ActivationModeKind::from(self.current_mode.as_str())
+    }
+
+    /// Returns `true` if the license was activated online and has since switched to offline activation.
+    pub fn switched_to_offline(&self) -> bool {
+        self.initial() == ActivationModeKind::Online && self.current() == ActivationModeKind::Offline
+    }
+}
+
+/// Represents the mode of an activation, as returned in [`ActivationMode`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(into = "String", from = "String"))]
+pub enum ActivationModeKind {
+    /// The activation was created or is synced online (`online`).
+    Online,
+    /// The activation was created or is synced using offline activation files (`offline`).
+    Offline,
+    /// Any other mode, as returned by the LexActivator library.
+    Other(String),
+}
+
+impl ActivationModeKind {
+    /// Returns the mode as returned by the LexActivator library.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ActivationModeKind::Online => "online",
+            ActivationModeKind::Offline => "offline",
+            ActivationModeKind::Other(mode) => mode,
+        }
+    }
+}
+
+impl From<&str> for ActivationModeKind {
+    fn from(mode: &str) -> Self {
+        match mode {
+            "online" => ActivationModeKind::Online,
+            "offline" => ActivationModeKind::Offline,
+            mode => ActivationModeKind::Other(mode.to_string()),
+        }
+    }
+}
+
+impl From<String> for ActivationModeKind {
+    fn from(mode: String) -> Self {
+        ActivationModeKind::from(mode.as_str())
+    }
+}
+
+impl From<ActivationModeKind> for String {
+    fn from(mode: ActivationModeKind) -> Self {
+        mode.as_str().to_string()
+    }
+}
+
+impl FromStr for ActivationModeKind {
+    type Err = std::convert::Infallible;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        Ok(ActivationModeKind::from(mode))
+    }
+}
+
+impl fmt::Display for ActivationModeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Represents the type of a license.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(into = "String", from = "String"))]
+pub enum LicenseType {
+    /// The license is locked to the machines it is activated on (`node-locked`).
+    NodeLocked,
+    /// The license is a floating license leased from the Cryptlex servers (`hosted-floating`).
+    HostedFloating,
+    /// The license is a floating license leased from an on-premise LexFloatServer (`on-premise-floating`).
+    OnPremiseFloating,
+    /// Any other type, as returned by the LexActivator library.
+    Other(String),
+}
+
+impl LicenseType {
+    /// Returns the type as returned by the LexActivator library.
+    pub fn as_str(&self) -> &str {
+        match self {
+            LicenseType::NodeLocked => "node-locked",
+            LicenseType::HostedFloating => "hosted-floating",
+            LicenseType::OnPremiseFloating => "on-premise-floating",
+            LicenseType::Other(license_type) => license_type,
+        }
+    }
+
+    /// Returns `true` for the floating license types.
+    pub fn is_floating(&self) -> bool {
+        matches!(self, LicenseType::HostedFloating | LicenseType::OnPremiseFloating)
+    }
+}
+
+impl From<&str> for LicenseType {
+    fn from(license_type: &str) -> Self {
+        match license_type {
+            "node-locked" => LicenseType::NodeLocked,
+            "hosted-floating" => LicenseType::HostedFloating,
+            "on-premise-floating" => LicenseType::OnPremiseFloating,
+            license_type => LicenseType::Other(license_type.to_string()),
+        }
+    }
+}
+
+impl From<String> for LicenseType {
+    fn from(license_type: String) -> Self {
+        LicenseType::from(license_type.as_str())
+    }
+}
+
+impl From<LicenseType> for String {
+    fn from(license_type: LicenseType) -> Self {
+        license_type.as_str().to_string()
+    }
+}
+
+impl FromStr for LicenseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(license_type: &str) -> Result<Self, Self::Err> {
+        Ok(LicenseType::from(license_type))
+    }
+}
+
+impl fmt::Display for LicenseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Represents a metadata 
 #[derive(Debug, Deserialize, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Metadata {
     /// The key of the metadata.
     pub key: String,
@@ -67,7 +291,9 @@ pub struct Metadata {
 }
 
 /// Represents an organization address.
-#[derive(Debug, Deserialize, Default)] 
+#[derive(Debug, Clone, Deserialize, Default)] 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(default)]
 pub struct OrganizationAddress {
     /// The first line of the address.
     #[serde(rename = "addressLine1")]
@@ -88,6 +314,7 @@ pub struct OrganizationAddress {
 
 /// Represents a user license with information about various license parameters.
 #[derive(Debug, Deserialize)] 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserLicense {
     /// The allowed activations count of a license.
     #[serde(rename = "allowedActivations")]
@@ -107,11 +334,21 @@ pub struct UserLicense {
     #[serde(rename = "type")]
     pub license_type: String,
     /// The license metadata.
+    #[serde(default)]
     pub metadata: Vec<Metadata>
 }
 
+impl UserLicense {
+    /// Returns the license type as a [`LicenseType`].
+    pub fn typed_license_type(&self) -> LicenseType {
+        LicenseType::from(self.license_type.as_str())
+    }
+}
+
 /// Represents a feature entitlement with details about its value.
-#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FeatureEntitlement {
     /// The name of the feature. 
     #[serde(rename = "featureName")]
@@ -123,14 +360,30 @@ pub struct FeatureEntitlement {
     #[serde(rename = "value")]
     pub value: String,
     /// Default value of the feature defined in the entitlement set; empty for features not inherited from an entitlement set.
-    #[serde(rename = "baseValue")]
+    #[serde(rename = "baseValue", default)]
     pub baseValue: String,
     /// Timestamp when the license feature entitlement will expire.
     #[serde(rename = "expiresAt")]
     pub expires_at : i64,
 }
 
+impl FeatureEntitlement {
+    /// Returns the expiry date of the feature entitlement, or `None` if it never expires.
+    pub fn expires_at_time(&self) -> Option<SystemTime> {
+        timestamp_to_system_time(self.expires_at)
+    }
+
+    /// Returns the time remaining until the feature entitlement expires, which is zero once it has expired,
+    /// or `None` if it never expires.
+    pub fn time_remaining(&self) -> Option<Duration> {
+        time_remaining(self.expires_at_time())
+    }
+}
+
 /// Represents various permission flags.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum PermissionFlags {
     /// This flag indicates that the application does not require admin or root permissions to run
@@ -144,6 +397,11 @@ pub enum PermissionFlags {
     LA_IN_MEMORY = 4,
 }
 
+/// Parses JSON returned by the LexActivator library, keeping the raw JSON in the error if it is malformed.
+fn parse_json<T: serde::de::DeserializeOwned>(json: String) -> Result<T, LexActivatorError> {
+    serde_json::from_str(&json).map_err(|error| LexActivatorError::JsonParse(JsonParseError::new(json, error)))
+}
+
 // --------------- Setter functions ------------------------
 
 /// Embeds the Product.dat file in the application.
@@ -159,8 +417,6 @@ pub enum PermissionFlags {
 /// # Returns
 /// 
 /// Returns `Ok(())` if the product data is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
-
 pub fn set_product_data(product_data: String) -> Result<(), LexActivatorError> {
 
     let status: i32;
@@ -177,7 +433,7 @@ pub fn set_product_data(product_data: String) -> Result<(), LexActivatorError> {
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -188,26 +444,25 @@ pub fn set_product_data(product_data: String) -> Result<(), LexActivatorError> {
 /// # Arguments
 ///
 /// * `product_id` - A `string` value representing the unique product id of your application as mentioned
-///                  on the product page in the dashboard.
+///   on the product page in the dashboard.
 ///
 /// * `permission_flags` - Depending on your application's requirements, choose one of 
-///             the following values: LA_SYSTEM, LA_USER, LA_IN_MEMORY, LA_ALL_USERS.
+///   the following values: LA_SYSTEM, LA_USER, LA_IN_MEMORY, LA_ALL_USERS.
 ///      
 ///     - `LA_USER`: This flag indicates that the application does not require
-///        admin or root permissions to run.
+///       admin or root permissions to run.
 ///        
 ///     - `LA_SYSTEM`: This flag indicates that the application must be run with admin or root permissions.
 /// 
 ///     - `LA_ALL_USERS`: This flag is specifically designed for Windows and should be used for system-wide activations.
 /// 
 ///     - `LA_IN_MEMORY`: This flag will store activation data in memory. Thus, requires re-activation
-///        on every start of the application and should only be used in floating licenses.
-        
+///       on every start of the application and should only be used in floating licenses.
+///
 /// 
 /// # Returns
 ///
 /// Returns `Ok(())` if the data directory is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_product_id(product_id: String, permission_flags: PermissionFlags) -> Result<(), LexActivatorError> {
     let status: i32;
     let c_flags: c_uint = permission_flags as u32 as c_uint;
@@ -225,7 +480,7 @@ pub fn set_product_id(product_id: String, permission_flags: PermissionFlags) ->
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     } 
 }
 
@@ -236,12 +491,11 @@ pub fn set_product_id(product_id: String, permission_flags: PermissionFlags) ->
 /// # Arguments
 /// 
 /// * `data_dir` - A `string` value representing the absolute path of the directory
-///               where LexActivator should store the activation data.
+///   where LexActivator should store the activation data.
 /// 
 /// # Returns
 /// 
 /// Returns `Ok(())` if the data directory is set successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_data_directory(data_dir: String) -> Result<(), LexActivatorError> {
 
     let status: i32;
@@ -258,7 +512,7 @@ pub fn set_data_directory(data_dir: String) -> Result<(), LexActivatorError> {
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -273,7 +527,6 @@ pub fn set_data_directory(data_dir: String) -> Result<(), LexActivatorError> {
 /// * `enable` - 0 or 1 to disable or enable logging.
 ///
 /// Returns `Ok(())` if the debug mode is enabled successfully.
-
 pub fn set_debug_mode(enable: u32) {
     let c_enable: c_uint = enable as c_uint;
     unsafe { SetDebugMode(c_enable) };
@@ -294,14 +547,13 @@ pub fn set_debug_mode(enable: u32) {
 /// * `mode` - False or True to disable or enable caching.
 ///
 /// Returns `Ok(())` if mode is set successfully.
-
 pub fn set_cache_mode(mode: bool) -> Result<(), LexActivatorError> {
     let c_mode: c_uint = if mode { 1 } else { 0 };  
     let status = unsafe { SetCacheMode(c_mode) };   
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -314,7 +566,6 @@ pub fn set_cache_mode(mode: bool) -> Result<(), LexActivatorError> {
 /// # Returns
 /// 
 /// Returns `Ok(())` if the custom device fingerprint is set successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_custom_device_fingerprint(device_fingerprint: String) -> Result<(), LexActivatorError> {
 
     let status: i32;
@@ -331,7 +582,7 @@ pub fn set_custom_device_fingerprint(device_fingerprint: String) -> Result<(), L
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -344,7 +595,6 @@ pub fn set_custom_device_fingerprint(device_fingerprint: String) -> Result<(), L
 /// # Returns
 ///
 /// Returns `Ok(())` if the license key is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
- 
 pub fn set_license_key(license_key: String) -> Result<(), LexActivatorError> {
 
     let status: i32;
@@ -361,7 +611,7 @@ pub fn set_license_key(license_key: String) -> Result<(), LexActivatorError> {
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -378,7 +628,6 @@ pub fn set_license_key(license_key: String) -> Result<(), LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(())` if the license user credentials are set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_license_user_credential(email: String, password: String) -> Result<(), LexActivatorError> {
     
     let status: i32;
@@ -397,7 +646,7 @@ pub fn set_license_user_credential(email: String, password: String) -> Result<()
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -416,27 +665,23 @@ pub fn set_license_user_credential(email: String, password: String) -> Result<()
 /// # Returns
 ///
 /// Returns `Ok(())` if the license closure callback is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_license_callback<F>(closure: F) -> Result<(), LexActivatorError>
 where
     F: Fn(LexActivatorCode) + Clone + Send + 'static,
 {
-    let mut callback_function = CALLBACK_FUNCTION.lock().unwrap();
-    callback_function.replace(Box::new(closure));
+    CALLBACK_FUNCTION.lock().unwrap_or_else(PoisonError::into_inner).replace(Box::new(closure));
     let status: i32 = unsafe { SetLicenseCallback(wrapper) };
 
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
 /// Unset the current license closure callback.
-
 pub fn unset_license_callback() {
-    let mut callback_function = CALLBACK_FUNCTION.lock().unwrap();
-    *callback_function = None;
+    CALLBACK_FUNCTION.lock().unwrap_or_else(PoisonError::into_inner).take();
 }
 
 /// Sets the activation lease duration.
@@ -448,14 +693,13 @@ pub fn unset_license_callback() {
 /// # Returns
 ///
 /// Returns `Ok(())` if the activation lease duration is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_activation_lease_duration(lease_duration: i64) -> Result<(), LexActivatorError> {
     let c_lease_duration: c_longlong = lease_duration as c_longlong;
     let status = unsafe { SetActivationLeaseDuration(c_lease_duration) };
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -469,7 +713,6 @@ pub fn set_activation_lease_duration(lease_duration: i64) -> Result<(), LexActiv
 /// # Returns
 ///
 /// Returns `Ok(())` if the activation metadata is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_activation_metadata(key: String, value: String) -> Result<(), LexActivatorError>  {
     let status: i32;
     #[cfg(windows)]
@@ -487,7 +730,7 @@ pub fn set_activation_metadata(key: String, value: String) -> Result<(), LexActi
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -501,7 +744,6 @@ pub fn set_activation_metadata(key: String, value: String) -> Result<(), LexActi
 /// # Returns
 ///
 /// Returns `Ok(())` if the trial activation metadata is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_trial_activation_metadata(key: String, value: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -519,7 +761,7 @@ pub fn set_trial_activation_metadata(key: String, value: String) -> Result<(), L
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -532,7 +774,6 @@ pub fn set_trial_activation_metadata(key: String, value: String) -> Result<(), L
 /// # Returns
 ///
 /// Returns `Ok(())` if the release version is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_release_version(version: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -548,7 +789,7 @@ pub fn set_release_version(version: String) -> Result<(), LexActivatorError> {
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -561,14 +802,13 @@ pub fn set_release_version(version: String) -> Result<(), LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(())` if the release published date is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_release_published_date(release_published_date: u32) -> Result<(), LexActivatorError>{
     let c_release_published_date: c_uint = release_published_date as c_uint;
     let status = unsafe { SetReleasePublishedDate(c_release_published_date) };
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -581,7 +821,6 @@ pub fn set_release_published_date(release_published_date: u32) -> Result<(), Lex
 /// # Returns
 ///
 /// Returns `Ok(())` if the release platform is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_release_platform(platform: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -597,7 +836,7 @@ pub fn set_release_platform(platform: String) -> Result<(), LexActivatorError> {
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -610,7 +849,6 @@ pub fn set_release_platform(platform: String) -> Result<(), LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(())` if the release channel is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_release_channel(channel: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -626,7 +864,7 @@ pub fn set_release_channel(channel: String) -> Result<(), LexActivatorError> {
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -640,7 +878,6 @@ pub fn set_release_channel(channel: String) -> Result<(), LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(())` if the offline activation request meter attribute uses are set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_offline_activation_request_meter_attribute_uses(name: String, uses: i32) -> Result<(), LexActivatorError>{
     let status: i32;
     let c_uses: c_uint = uses as c_uint;
@@ -657,7 +894,7 @@ pub fn set_offline_activation_request_meter_attribute_uses(name: String, uses: i
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -670,7 +907,6 @@ pub fn set_offline_activation_request_meter_attribute_uses(name: String, uses: i
 /// # Returns
 ///
 /// Returns `Ok(())` if the network proxy is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_network_proxy(proxy: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -686,7 +922,7 @@ pub fn set_network_proxy(proxy: String) -> Result<(), LexActivatorError> {
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -699,7 +935,6 @@ pub fn set_network_proxy(proxy: String) -> Result<(), LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(())` if the Cryptlex host is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_cryptlex_host(host: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -715,7 +950,7 @@ pub fn set_cryptlex_host(host: String) -> Result<(), LexActivatorError> {
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -728,7 +963,6 @@ pub fn set_cryptlex_host(host: String) -> Result<(), LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(())` if the two_factor_authentication_code is set successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn set_two_factor_authentication_code(two_factor_authentication_code: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -744,36 +978,18 @@ pub fn set_two_factor_authentication_code(two_factor_authentication_code: String
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
 // ------------------- Getter Functions --------------------
 
 pub fn get_product_metadata(key: String) -> Result<String, LexActivatorError> {
-    
-    let status: i32;
-    const LENGTH: usize = 256;
-    let product_metadata_value: String;
     #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        let utf16_ptr =  to_utf16(key);
-        status = unsafe { GetProductMetadata(utf16_ptr.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        product_metadata_value = utf16_to_string(&buffer);
-    }
+    let c_key = to_utf16(key);
     #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        let key_cstring: CString  = string_to_cstring(key)?;
-        status = unsafe { GetProductMetadata(key_cstring.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        product_metadata_value = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(product_metadata_value)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    let c_key: CString = string_to_cstring(key)?;
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetProductMetadata(c_key.as_ptr(), buffer, length) })
 }
 
 /// Retrieves the name of the product version.
@@ -784,28 +1000,8 @@ pub fn get_product_metadata(key: String) -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(String)` with the name of the product version if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_product_version_name() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256;
-    let product_version_name: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetProductVersionName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        product_version_name = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetProductVersionName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        product_version_name = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(product_version_name)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetProductVersionName(buffer, length) })
 }
 
 /// Retrieves the display name of the product version.
@@ -816,31 +1012,8 @@ pub fn get_product_version_name() -> Result<String, LexActivatorError> {
 /// # Returns
 /// Returns `Ok(String)` with the display name of the product version if it is retrieved successfully.
 /// If an error occurs, an `Err` containing the `LexActivatorError` is returned.
-
 pub fn get_product_version_display_name() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let product_version_display_name: String;
-    
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetProductVersionDisplayName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        product_version_display_name = utf16_to_string(&buffer);
-    }
-
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetProductVersionDisplayName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        product_version_display_name = c_char_to_string(&buffer);
-    }
-
-    if status == 0 {
-        Ok(product_version_display_name)
-    } else {
-        Err(LexActivatorError::from(status))
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetProductVersionDisplayName(buffer, length) })
 }
 
 /// Retrieves the feature flag of a specific product version.
@@ -855,37 +1028,20 @@ pub fn get_product_version_display_name() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(ProductVersionFeatureFlag)` with the feature flag information if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_product_version_feature_flag(name: String) -> Result<ProductVersionFeatureFlag, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
     let feature_name: String = name.clone();
-    let data: String;
     let mut c_enabled: c_uint = 0;
     #[cfg(windows)]
-    {
-        let c_name = to_utf16(name);
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetProductVersionFeatureFlag(c_name.as_ptr(), &mut c_enabled, buffer.as_mut_ptr(), LENGTH as c_uint) };
-        data = utf16_to_string(&buffer);
-    }
+    let c_name = to_utf16(name);
     #[cfg(not(windows))]
-    {
-        let c_name = string_to_cstring(name)?;
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetProductVersionFeatureFlag(c_name.as_ptr(), &mut c_enabled, buffer.as_mut_ptr(), LENGTH as c_uint) };
-        data = c_char_to_string(&buffer);
-    }
+    let c_name = string_to_cstring(name)?;
+    let data = get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetProductVersionFeatureFlag(c_name.as_ptr(), &mut c_enabled, buffer, length) })?;
     let product_version_feature_flag = ProductVersionFeatureFlag {
         name: feature_name,
         enabled: u32_to_bool(c_enabled),
-        data: data
+        data
     };
-    if status == 0 {
-        Ok(product_version_feature_flag)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    Ok(product_version_feature_flag)
 }
 
 /// Retrieves the metadata associated with a license.
@@ -897,30 +1053,12 @@ pub fn get_product_version_feature_flag(name: String) -> Result<ProductVersionFe
 /// # Returns
 ///
 /// Returns `Ok(String)` with the metadata value if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_metadata(key: String) -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let license_metadata: String;
     #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        let c_key =  to_utf16(key);
-        status = unsafe { GetLicenseMetadata(c_key.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_metadata = utf16_to_string(&buffer);
-    }
+    let c_key = to_utf16(key);
     #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        let c_key: CString  = string_to_cstring(key)?;
-        status = unsafe { GetLicenseMetadata(c_key.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_metadata = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(license_metadata)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    let c_key: CString = string_to_cstring(key)?;
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseMetadata(c_key.as_ptr(), buffer, length) })
 }
 
 /// Retrieves the meter attribute of a license.
@@ -932,7 +1070,6 @@ pub fn get_license_metadata(key: String) -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(LicenseMeterAttribute)` with the meter attribute information if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_meterattribute(name: String) -> Result<LicenseMeterAttribute, LexActivatorError> {
     let status: i32;
     let meter_attribute_name: String = name.clone();
@@ -958,7 +1095,7 @@ pub fn get_license_meterattribute(name: String) -> Result<LicenseMeterAttribute,
     if status == 0 {
         Ok(meter_attribute)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -967,28 +1104,8 @@ pub fn get_license_meterattribute(name: String) -> Result<LicenseMeterAttribute,
 /// # Returns
 ///
 /// Returns `Ok(String)` with the license key if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_key() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let license_key: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseKey(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_key = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseKey(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_key = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(license_key)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseKey(buffer, length) })
 }
 
 /// Retrieves the number of allowed activations for the license.
@@ -996,14 +1113,13 @@ pub fn get_license_key() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(i64)` with the number of allowed activations if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_allowed_activations() -> Result<i64, LexActivatorError> {
     let mut allowed_activations: c_longlong = 0;
     let status = unsafe { GetLicenseAllowedActivations(&mut allowed_activations) };
     if status == 0 {
         Ok(allowed_activations)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -1012,14 +1128,13 @@ pub fn get_license_allowed_activations() -> Result<i64, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the total number of activations if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_total_activations() -> Result<u32, LexActivatorError> {
     let mut total_activations: c_uint = 0;
     let status = unsafe { GetLicenseTotalActivations(&mut total_activations) };
     if status == 0 {
         Ok(total_activations)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -1028,14 +1143,13 @@ pub fn get_license_total_activations() -> Result<u32, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(i64)` with the number of allowed deactivations if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_allowed_deactivations() -> Result<i64, LexActivatorError> {
     let mut allowed_deactivations: c_longlong = 0;
     let status = unsafe { GetLicenseAllowedDeactivations(&mut allowed_deactivations) };
     if status == 0 {
         Ok(allowed_deactivations)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -1044,14 +1158,13 @@ pub fn get_license_allowed_deactivations() -> Result<i64, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the total number of deactivations if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_total_deactivations() -> Result<u32, LexActivatorError> {
     let mut total_deactivations: c_uint = 0;
     let status = unsafe { GetLicenseTotalDeactivations(&mut total_deactivations) };
     if status == 0 {
         Ok(total_deactivations)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -1060,33 +1173,49 @@ pub fn get_license_total_deactivations() -> Result<u32, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the license creation date timestamp if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_creation_date() -> Result<u32, LexActivatorError> {
     let mut creation_date:c_uint = 0;
     let status = unsafe { GetLicenseCreationDate(&mut creation_date) };
     if status == 0 {
         Ok(creation_date)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
+/// Retrieves the license creation date as a `SystemTime`.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(SystemTime))` with the license creation date, or `Ok(None)` if the date is not available. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_license_creation_time() -> Result<Option<SystemTime>, LexActivatorError> {
+    get_license_creation_date().map(timestamp_to_system_time)
+}
+
 /// Retrieves the license activation date timestamp.
 ///
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the license activation date timestamp if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_activation_date() -> Result<u32, LexActivatorError> {
     let mut activation_date:c_uint = 0;
     let status = unsafe { GetLicenseActivationDate(&mut activation_date) };
     if status == 0 {
         Ok(activation_date)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
+/// Retrieves the license activation date as a `SystemTime`.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(SystemTime))` with the license activation date, or `Ok(None)` if the date is not available. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_license_activation_time() -> Result<Option<SystemTime>, LexActivatorError> {
+    get_license_activation_date().map(timestamp_to_system_time)
+}
+
 /// Retrieves the activation last synced date timestamp.
 /// 
 /// Initially, this timestamp matches the activation creation date, and then updates with each successful server sync.
@@ -1094,76 +1223,98 @@ pub fn get_license_activation_date() -> Result<u32, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the activation last synced date timestamp if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_activation_last_synced_date() -> Result<u32, LexActivatorError> {
     let mut last_synced_date: c_uint = 0;
     let status = unsafe { GetActivationLastSyncedDate(&mut last_synced_date) };
     if status == 0 {
         Ok(last_synced_date)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
+/// Retrieves the activation last synced date as a `SystemTime`.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(SystemTime))` with the activation last synced date, or `Ok(None)` if the activation has never been synced. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_activation_last_synced_time() -> Result<Option<SystemTime>, LexActivatorError> {
+    get_activation_last_synced_date().map(timestamp_to_system_time)
+}
+
 /// Retrieves the expiry date of the license.
 ///
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the expiry date (in seconds since Unix epoch) if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_expiry_date() -> Result<u32, LexActivatorError> {
     let mut expiry_date: c_uint = 0;
     let status = unsafe { GetLicenseExpiryDate(&mut expiry_date) };
     if status == 0 {
         Ok(expiry_date)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
+/// Retrieves the expiry date of the license as a `SystemTime`.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(SystemTime))` with the expiry date of the license, or `Ok(None)` if the license never expires. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_license_expiry_time() -> Result<Option<SystemTime>, LexActivatorError> {
+    get_license_expiry_date().map(timestamp_to_system_time)
+}
+
+/// Retrieves the time remaining until the license expires.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(Duration))` with the time remaining, which is zero once the date has passed, or `Ok(None)` if the license never expires. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_license_time_remaining() -> Result<Option<Duration>, LexActivatorError> {
+    get_license_expiry_time().map(time_remaining)
+}
+
 /// Retrieves the maintenance expiry date of the license.
 ///
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the maintenance expiry date (in seconds since Unix epoch) if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_maintenance_expiry_date() -> Result<u32, LexActivatorError> {
     let mut expiry_date: c_uint = 0;
     let status = unsafe { GetLicenseMaintenanceExpiryDate(&mut expiry_date) };
     if status == 0 {
         Ok(expiry_date)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
+/// Retrieves the maintenance expiry date of the license as a `SystemTime`.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(SystemTime))` with the maintenance expiry date of the license, or `Ok(None)` if the maintenance never expires. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_license_maintenance_expiry_time() -> Result<Option<SystemTime>, LexActivatorError> {
+    get_license_maintenance_expiry_date().map(timestamp_to_system_time)
+}
+
+/// Retrieves the time remaining until the maintenance of the license expires.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(Duration))` with the time remaining, which is zero once the date has passed, or `Ok(None)` if the maintenance never expires. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_license_maintenance_time_remaining() -> Result<Option<Duration>, LexActivatorError> {
+    get_license_maintenance_expiry_time().map(time_remaining)
+}
+
 /// Retrieves the maximum allowed release version for the license.
 ///
 /// # Returns
 ///
 /// Returns `Ok(String)` with the maximum allowed release version if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_max_allowed_release_version() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let max_allowed_release_version: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseMaxAllowedReleaseVersion(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        max_allowed_release_version = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseMaxAllowedReleaseVersion(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        max_allowed_release_version = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(max_allowed_release_version)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseMaxAllowedReleaseVersion(buffer, length) })
 }
 
 /// Retrieves the user's email associated with the license.
@@ -1171,28 +1322,8 @@ pub fn get_license_max_allowed_release_version() -> Result<String, LexActivatorE
 /// # Returns
 ///
 /// Returns `Ok(String)` with the user's email if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_user_email() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let user_email: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseUserEmail(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_email = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseUserEmail(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_email = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(user_email)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseUserEmail(buffer, length) })
 }
 
 /// Retrieves the user's name associated with the license.
@@ -1200,28 +1331,8 @@ pub fn get_license_user_email() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(String)` with the user's name if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_user_name() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let user_name: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseUserName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_name = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseUserName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_name = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(user_name)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseUserName(buffer, length) })
 }
 
 /// Retrieves the user's company associated with the license.
@@ -1229,28 +1340,8 @@ pub fn get_license_user_name() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(String)` with the user's company if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_user_company() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let user_company: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseUserCompany(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_company = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseUserCompany(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_company = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(user_company)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseUserCompany(buffer, length) })
 }
 
 /// Retrieves the metadata value associated with the license user.
@@ -1262,30 +1353,12 @@ pub fn get_license_user_company() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(String)` with the metadata value if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_user_metadata(key: String) -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let user_metadata: String;
     #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        let c_key = to_utf16(key);
-        status = unsafe { GetLicenseUserMetadata(c_key.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_metadata = utf16_to_string(&buffer);
-    }
+    let c_key = to_utf16(key);
     #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        let c_key: CString  = string_to_cstring(key)?;
-        status = unsafe { GetLicenseUserMetadata(c_key.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_metadata = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(user_metadata)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    let c_key: CString = string_to_cstring(key)?;
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseUserMetadata(c_key.as_ptr(), buffer, length) })
 }
 
 /// Retrieves the organization name associated with the license.
@@ -1293,28 +1366,8 @@ pub fn get_license_user_metadata(key: String) -> Result<String, LexActivatorErro
 /// # Returns
 ///
 /// Returns `Ok(String)` with the organization name if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_organization_name() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let organization_name: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseOrganizationName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        organization_name = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseOrganizationName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        organization_name = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(organization_name)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseOrganizationName(buffer, length) })
 }
 
 /// Retrieves the organization address associated with the license.
@@ -1322,32 +1375,12 @@ pub fn get_license_organization_name() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(OrganizationAddress)` with the organization address if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_organization_address() -> Result<OrganizationAddress, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let org_address_json: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseOrganizationAddressInternal(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        org_address_json = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseOrganizationAddressInternal(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        org_address_json = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        if org_address_json.trim().is_empty() {
-            Ok(OrganizationAddress::default())
-        } else {
-            let org_address: OrganizationAddress = serde_json::from_str(&org_address_json).expect("Failed to parse JSON");
-            Ok(org_address)
-        }
+    let org_address_json = get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseOrganizationAddressInternal(buffer, length) })?;
+    if org_address_json.trim().is_empty() {
+        Ok(OrganizationAddress::default())
     } else {
-        return Err(LexActivatorError::from(status));
+        parse_json(org_address_json)
     }
 }
 
@@ -1360,33 +1393,13 @@ pub fn get_license_organization_address() -> Result<OrganizationAddress, LexActi
 /// # Returns
 ///
 /// Returns `Ok(Vec<UserLicense>)` with the user licenses if retrieved successfully. If an error occurs, an `Err` containing the `LexActivatorError` is returned. 
-
 pub fn get_user_licenses() -> Result<Vec<UserLicense>, LexActivatorError> {
-    let status: i32;
     const LENGTH: usize = 1024;
-    let user_licenses_json: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetUserLicensesInternal(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_licenses_json = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetUserLicensesInternal(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        user_licenses_json = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        if user_licenses_json.is_empty() {
-            Ok(Vec::new())
-        } else {
-            let user_licenses: Vec<UserLicense> = serde_json::from_str(&user_licenses_json).expect("Failed to parse JSON");
-            Ok(user_licenses)
-        }
-        
+    let user_licenses_json = get_native_string(LENGTH, |buffer, length| unsafe { GetUserLicensesInternal(buffer, length) })?;
+    if user_licenses_json.is_empty() {
+        Ok(Vec::new())
     } else {
-        Err(LexActivatorError::from(status))
+        parse_json(user_licenses_json)
     }
 }
 
@@ -1395,28 +1408,8 @@ pub fn get_user_licenses() -> Result<Vec<UserLicense>, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(String)` with the entitlement set name of the license if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_entitlement_set_name() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256;
-    let license_entitlement_set_name: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseEntitlementSetName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_entitlement_set_name = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseEntitlementSetName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_entitlement_set_name = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(license_entitlement_set_name)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseEntitlementSetName(buffer, length) })
 }
 
 /// Retrieves the license entitlement set display name.
@@ -1424,28 +1417,8 @@ pub fn get_license_entitlement_set_name() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(String)` with the entitlement set display name of the license if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_entitlement_set_display_name() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256;
-    let license_entitlement_set_display_name: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseEntitlementSetDisplayName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_entitlement_set_display_name = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseEntitlementSetDisplayName(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_entitlement_set_display_name = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(license_entitlement_set_display_name)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseEntitlementSetDisplayName(buffer, length) })
 }
 
 /// Retrieves the license entitlement set display tier.
@@ -1453,14 +1426,13 @@ pub fn get_license_entitlement_set_display_name() -> Result<String, LexActivator
 /// # Returns
 ///
 /// Returns `Ok(String)` with the entitlement set tier of the license if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_entitlement_set_tier() -> Result<i64, LexActivatorError> {
     let mut tier: c_longlong = 0;
     let status = unsafe { GetLicenseEntitlementSetTier(&mut tier) };
     if status == 0 {
         Ok(tier)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -1470,33 +1442,12 @@ pub fn get_license_entitlement_set_tier() -> Result<i64, LexActivatorError> {
 ///
 /// Returns `Ok(Vec<FeatureEntitlement>)` with the feature entitlements of the license if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
 pub fn get_feature_entitlements() -> Result<Vec<FeatureEntitlement>, LexActivatorError> {
-    let status: i32;
     const LENGTH: usize = 4096;
-    let feature_entitlements_json: String;
-
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetFeatureEntitlementsInternal(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        feature_entitlements_json = utf16_to_string(&buffer);
-    }
-    
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetFeatureEntitlementsInternal(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        feature_entitlements_json = c_char_to_string(&buffer);
-    }
-
-    if status == 0 {
-        if feature_entitlements_json.is_empty() {
-            Ok(Vec::new())
-        } else {
-            let feature_entitlements: Vec<FeatureEntitlement> = serde_json::from_str(&feature_entitlements_json).expect("Failed to parse JSON");
-            Ok(feature_entitlements)
-        }
+    let feature_entitlements_json = get_native_string(LENGTH, |buffer, length| unsafe { GetFeatureEntitlementsInternal(buffer, length) })?;
+    if feature_entitlements_json.is_empty() {
+        Ok(Vec::new())
     } else {
-        Err(LexActivatorError::from(status))
+        parse_json(feature_entitlements_json)
     }
 }
 
@@ -1510,32 +1461,13 @@ pub fn get_feature_entitlements() -> Result<Vec<FeatureEntitlement>, LexActivato
 ///
 /// Returns `Ok(FeatureEntitlement)` with the feature entitlement of the license if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
 pub fn get_feature_entitlement(feature_name: String) -> Result<FeatureEntitlement, LexActivatorError> {
-    let status: i32;
     const LENGTH: usize = 1024;
-    let feature_entitlement_json: String;
-
     #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        let c_name = to_utf16(feature_name);
-        status = unsafe { GetFeatureEntitlementInternal(c_name.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        feature_entitlement_json = utf16_to_string(&buffer);
-    }
-    
+    let c_name = to_utf16(feature_name);
     #[cfg(not(windows))]
-    {
-        let c_name = string_to_cstring(feature_name)?;
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetFeatureEntitlementInternal(c_name.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        feature_entitlement_json = c_char_to_string(&buffer);
-    }
-
-    if status == 0 {
-        let feature_entitlement: FeatureEntitlement = serde_json::from_str(&feature_entitlement_json).expect("Failed to parse JSON");
-        Ok(feature_entitlement)
-    } else {
-        Err(LexActivatorError::from(status))
-    }
+    let c_name = string_to_cstring(feature_name)?;
+    let feature_entitlement_json = get_native_string(LENGTH, |buffer, length| unsafe { GetFeatureEntitlementInternal(c_name.as_ptr(), buffer, length) })?;
+    parse_json(feature_entitlement_json)
 }
 
 /// Retrieves the type of the license.
@@ -1543,28 +1475,17 @@ pub fn get_feature_entitlement(feature_name: String) -> Result<FeatureEntitlemen
 /// # Returns
 ///
 /// Returns `Ok(String)` with the license type if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_license_type() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let license_type: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseType(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_type = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLicenseType(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        license_type = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(license_type)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLicenseType(buffer, length) })
+}
+
+/// Retrieves the type of the license as a [`LicenseType`].
+///
+/// # Returns
+///
+/// Returns `Ok(LicenseType)` with the license type if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
+pub fn get_typed_license_type() -> Result<LicenseType, LexActivatorError> {
+    get_license_type().map(LicenseType::from)
 }
 
 /// Retrieves the activation id.
@@ -1572,28 +1493,8 @@ pub fn get_license_type() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(String)` with the activation id if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_activation_id() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256;
-    let activation_id: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetActivationId(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        activation_id = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetActivationId(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        activation_id = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(activation_id)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetActivationId(buffer, length) })
 }
 
 /// Retrieves the metadata value associated with the specified key for the activation.
@@ -1605,30 +1506,12 @@ pub fn get_activation_id() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(String)` with the metadata value if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_activation_metadata(key: String) -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let activation_metadata: String;
     #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        let c_key = to_utf16(key);
-        status = unsafe { GetActivationMetadata(c_key.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        activation_metadata = utf16_to_string(&buffer);
-    }
+    let c_key = to_utf16(key);
     #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        let c_key: CString  = string_to_cstring(key)?;
-        status = unsafe { GetActivationMetadata(c_key.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        activation_metadata = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(activation_metadata)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    let c_key: CString = string_to_cstring(key)?;
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetActivationMetadata(c_key.as_ptr(), buffer, length) })
 }
 
 /// Retrieves the initial and current activation mode.
@@ -1636,37 +1519,15 @@ pub fn get_activation_metadata(key: String) -> Result<String, LexActivatorError>
 /// # Returns
 ///
 /// Returns `Ok(ActivationMode)` with the initial and current activation mode if they are retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_activation_mode() -> Result<ActivationMode, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let initial_activation_mode: String;
-    let current_activation_mode: String;
-    #[cfg(windows)]
-    {
-        let mut initial_mode_buffer: [u16; LENGTH] = [0; LENGTH];
-        let mut current_mode_buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetActivationMode(initial_mode_buffer.as_mut_ptr(), LENGTH as c_uint, current_mode_buffer.as_mut_ptr(), LENGTH as c_uint) };
-        initial_activation_mode = utf16_to_string(&initial_mode_buffer);
-        current_activation_mode = utf16_to_string(&current_mode_buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut initial_mode_buffer: [c_char; LENGTH] = [0; LENGTH];
-        let mut current_mode_buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetActivationMode(initial_mode_buffer.as_mut_ptr(), LENGTH as c_uint, current_mode_buffer.as_mut_ptr(), LENGTH as c_uint) };
-        initial_activation_mode = c_char_to_string(&initial_mode_buffer);
-        current_activation_mode = c_char_to_string(&current_mode_buffer);
-    }
+    let [initial_activation_mode, current_activation_mode] = get_native_strings(INITIAL_BUFFER_LENGTH, |[initial_mode_buffer, current_mode_buffer], length| unsafe {
+        GetActivationMode(initial_mode_buffer, length, current_mode_buffer, length)
+    })?;
     let activation_mode = ActivationMode {
         initial_mode: initial_activation_mode,
         current_mode: current_activation_mode,
     };
-    if status == 0 {
-        Ok(activation_mode)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    Ok(activation_mode)
 }
 
 /// Retrieves the number of uses of the specified metered attribute for the activation.
@@ -1678,7 +1539,6 @@ pub fn get_activation_mode() -> Result<ActivationMode, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the number of uses of the metered attribute if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_activation_meter_attribute_uses(name: String) -> Result<u32, LexActivatorError> {
     let status: i32;
     let mut count: c_uint = 0;
@@ -1695,7 +1555,7 @@ pub fn get_activation_meter_attribute_uses(name: String) -> Result<u32, LexActiv
     if status == 0 {
         Ok(count)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -1704,32 +1564,48 @@ pub fn get_activation_meter_attribute_uses(name: String) -> Result<u32, LexActiv
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the expiry date of the server sync grace period if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_server_sync_grace_period_expiry_date() -> Result<u32, LexActivatorError> {
-    let status: i32;
+    
     let mut expiry_date: c_uint = 0;
-    status = unsafe { GetServerSyncGracePeriodExpiryDate(&mut expiry_date) };
+    let status: i32 = unsafe { GetServerSyncGracePeriodExpiryDate(&mut expiry_date) };
     if status == 0 {
         Ok(expiry_date)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
+/// Retrieves the expiry date of the server sync grace period for the activation as a `SystemTime`.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(SystemTime))` with the expiry date of the server sync grace period for the activation, or `Ok(None)` if no grace period applies. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_server_sync_grace_period_expiry_time() -> Result<Option<SystemTime>, LexActivatorError> {
+    get_server_sync_grace_period_expiry_date().map(timestamp_to_system_time)
+}
+
+/// Retrieves the time remaining until the server sync grace period for the activation is over.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(Duration))` with the time remaining, which is zero once the date has passed, or `Ok(None)` if no grace period applies. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_server_sync_grace_period_time_remaining() -> Result<Option<Duration>, LexActivatorError> {
+    get_server_sync_grace_period_expiry_time().map(time_remaining)
+}
+
 /// Retrieves the error code that caused the activation data to be cleared.
 ///
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the error code that caused the activation data to be cleared if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_last_activation_error() -> Result<u32, LexActivatorError> {
-    let status: i32;
+    
     let mut error_code: c_uint = 0;
-    status = unsafe { GetLastActivationError(&mut error_code) };
+    let status: i32 = unsafe { GetLastActivationError(&mut error_code) };
     if status == 0 {
         Ok(error_code)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -1742,30 +1618,12 @@ pub fn get_last_activation_error() -> Result<u32, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(String)` with the metadata value if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_trial_activation_metadata(key: String) -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let trial_activation_metadata: String;
     #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        let c_key = to_utf16(key);
-        status = unsafe { GetTrialActivationMetadata(c_key.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        trial_activation_metadata = utf16_to_string(&buffer);
-    }
+    let c_key = to_utf16(key);
     #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        let c_key: CString  = string_to_cstring(key)?;
-        status = unsafe { GetTrialActivationMetadata(c_key.as_ptr(), buffer.as_mut_ptr(), LENGTH as c_uint) };
-        trial_activation_metadata = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(trial_activation_metadata)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    let c_key: CString = string_to_cstring(key)?;
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetTrialActivationMetadata(c_key.as_ptr(), buffer, length) })
 }
 
 /// Retrieves the expiry date of the trial activation.
@@ -1773,45 +1631,42 @@ pub fn get_trial_activation_metadata(key: String) -> Result<String, LexActivator
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the expiry date of the trial activation if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_trial_expiry_date() -> Result<u32, LexActivatorError> {
-    let status: i32;
+    
     let mut trial_expiry_date: c_uint = 0;
-    status = unsafe { GetTrialExpiryDate(&mut trial_expiry_date) };
+    let status: i32 = unsafe { GetTrialExpiryDate(&mut trial_expiry_date) };
     if status == 0 {
         Ok(trial_expiry_date)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
+/// Retrieves the expiry date of the trial activation as a `SystemTime`.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(SystemTime))` with the expiry date of the trial activation, or `Ok(None)` if the trial never expires. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_trial_expiry_time() -> Result<Option<SystemTime>, LexActivatorError> {
+    get_trial_expiry_date().map(timestamp_to_system_time)
+}
+
+/// Retrieves the time remaining until the trial activation expires.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(Duration))` with the time remaining, which is zero once the date has passed, or `Ok(None)` if the trial never expires. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_trial_time_remaining() -> Result<Option<Duration>, LexActivatorError> {
+    get_trial_expiry_time().map(time_remaining)
+}
+
 /// Retrieves the ID of the trial activation.
 ///
 /// # Returns
 ///
 /// Returns `Ok(String)` with the trial ID if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_trial_id() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let trial_id: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetTrialId(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        trial_id = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetTrialId(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        trial_id = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(trial_id)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetTrialId(buffer, length) })
 }
 
 /// Retrieves the local expiry date of the trial activation.
@@ -1819,45 +1674,42 @@ pub fn get_trial_id() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(u32)` with the local expiry date of the trial activation if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_local_trial_expiry_date() -> Result<u32, LexActivatorError> {
-    let status: i32;
+    
     let mut trial_expiry_date: c_uint = 0;
-    status = unsafe { GetLocalTrialExpiryDate(&mut trial_expiry_date) };
+    let status: i32 = unsafe { GetLocalTrialExpiryDate(&mut trial_expiry_date) };
     if status == 0 {
         Ok(trial_expiry_date)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
+/// Retrieves the local expiry date of the trial activation as a `SystemTime`.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(SystemTime))` with the local expiry date of the trial activation, or `Ok(None)` if the local trial never expires. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_local_trial_expiry_time() -> Result<Option<SystemTime>, LexActivatorError> {
+    get_local_trial_expiry_date().map(timestamp_to_system_time)
+}
+
+/// Retrieves the time remaining until the local trial expires.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(Duration))` with the time remaining, which is zero once the date has passed, or `Ok(None)` if the local trial never expires. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_local_trial_time_remaining() -> Result<Option<Duration>, LexActivatorError> {
+    get_local_trial_expiry_time().map(time_remaining)
+}
+
 /// Retrieves the version of the LexActivator library.
 ///
 /// # Returns
 ///
 /// Returns `Ok(String)` with the library version if it is retrieved successfully, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn get_library_version() -> Result<String, LexActivatorError> {
-    let status: i32;
-    const LENGTH: usize = 256; // Set the appropriate buffer length
-    let library_version: String;
-    #[cfg(windows)]
-    {
-        let mut buffer: [u16; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLibraryVersion(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        library_version = utf16_to_string(&buffer);
-    }
-    #[cfg(not(windows))]
-    {
-        let mut buffer: [c_char; LENGTH] = [0; LENGTH];
-        status = unsafe { GetLibraryVersion(buffer.as_mut_ptr(), LENGTH as c_uint) };
-        library_version = c_char_to_string(&buffer);
-    }
-    if status == 0 {
-        Ok(library_version)
-    } else {
-        return Err(LexActivatorError::from(status));
-    }
+    get_native_string(INITIAL_BUFFER_LENGTH, |buffer, length| unsafe { GetLibraryVersion(buffer, length) })
 }
 
 // ------------------ Action Functions ------------------
@@ -1874,7 +1726,6 @@ pub fn get_library_version() -> Result<String, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the authentication is successful. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn authenticate_user(email: String, password: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -1892,7 +1743,7 @@ pub fn authenticate_user(email: String, password: String) -> Result<(), LexActiv
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -1905,7 +1756,6 @@ pub fn authenticate_user(email: String, password: String) -> Result<(), LexActiv
 /// # Returns
 ///
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the authentication is successful. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn authenticate_user_with_id_token(id_token: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -1921,7 +1771,7 @@ pub fn authenticate_user_with_id_token(id_token: String) -> Result<(), LexActiva
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -1932,7 +1782,6 @@ pub fn authenticate_user_with_id_token(id_token: String) -> Result<(), LexActiva
 /// # Returns
 ///
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the license activation is successful. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-  
 pub fn activate_license() -> Result<LexActivatorStatus, LexActivatorError> {
     let status = unsafe { ActivateLicense() };
     match status {
@@ -1953,7 +1802,6 @@ pub fn activate_license() -> Result<LexActivatorStatus, LexActivatorError> {
 /// # Returns
 /// 
 /// Returns `Ok(())` if the license activation is successful. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn activate_license_offline(file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -1984,7 +1832,6 @@ pub fn activate_license_offline(file_path: String) -> Result<LexActivatorStatus,
 /// # Returns
 ///
 /// Returns `Ok(())` if the offline activation request file generation is successful. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn generate_offline_activation_request(file_path: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -2000,7 +1847,7 @@ pub fn generate_offline_activation_request(file_path: String) -> Result<(), LexA
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -2011,7 +1858,6 @@ pub fn generate_offline_activation_request(file_path: String) -> Result<(), LexA
 /// # Returns
 ///
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the license deactivation is successful. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn deactivate_license() -> Result<LexActivatorStatus, LexActivatorError> {
     let status = unsafe { DeactivateLicense() };
     match status {
@@ -2030,7 +1876,6 @@ pub fn deactivate_license() -> Result<LexActivatorStatus, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(())` if the offline deactivation request file generation is successful. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn generate_offline_deactivation_request(file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -2061,7 +1906,6 @@ pub fn generate_offline_deactivation_request(file_path: String) -> Result<LexAct
 /// # Returns
 ///
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the license is genuine. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn is_license_genuine() -> Result<LexActivatorStatus, LexActivatorError> {
     let status = unsafe { IsLicenseGenuine() };
     match status {
@@ -2083,7 +1927,6 @@ pub fn is_license_genuine() -> Result<LexActivatorStatus, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the license is genuine. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn is_license_valid() -> Result<LexActivatorStatus, LexActivatorError> {
     let status = unsafe { IsLicenseValid() };
     match status {
@@ -2127,7 +1970,6 @@ pub fn sync_license_activation() -> Result<LexActivatorStatus, LexActivatorError
 /// # Returns
 /// 
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the trial has started successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn activate_trial() -> Result<LexActivatorStatus, LexActivatorError> {
     let status = unsafe { ActivateTrial() };
     match status {
@@ -2151,7 +1993,6 @@ pub fn activate_trial() -> Result<LexActivatorStatus, LexActivatorError> {
 /// # Returns
 ///
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the trial activation has been synced successfully. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
-
 pub fn sync_trial_activation() -> Result<LexActivatorStatus, LexActivatorError> {
     let status = unsafe { SyncTrialActivation() };
     match status {
@@ -2171,7 +2012,6 @@ pub fn sync_trial_activation() -> Result<LexActivatorStatus, LexActivatorError>
 /// # Returns
 /// 
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the trial has started successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn activate_trial_offline(file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -2201,7 +2041,6 @@ pub fn activate_trial_offline(file_path: String) -> Result<LexActivatorStatus, L
 /// # Returns
 /// 
 /// Returns `Ok(())` if the offline trial activation request file generation is successful. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn generate_offline_trial_activation_request(file_path: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -2217,7 +2056,7 @@ pub fn generate_offline_trial_activation_request(file_path: String) -> Result<()
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -2228,7 +2067,6 @@ pub fn generate_offline_trial_activation_request(file_path: String) -> Result<()
 /// # Returns
 /// 
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if trial has started and is genuine. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn is_trial_genuine() -> Result<LexActivatorStatus, LexActivatorError> {
     let status = unsafe { IsTrialGenuine() };
     match status {
@@ -2252,7 +2090,6 @@ pub fn is_trial_genuine() -> Result<LexActivatorStatus, LexActivatorError> {
 /// # Returns
 /// 
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the trial has started successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn activate_local_trial(trial_length: u32) -> Result<LexActivatorStatus, LexActivatorError> {
     let c_trial_length: c_uint = trial_length as c_uint;
     let status = unsafe { ActivateLocalTrial(c_trial_length) };
@@ -2273,7 +2110,6 @@ pub fn activate_local_trial(trial_length: u32) -> Result<LexActivatorStatus, Lex
 /// # Returns
 /// 
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if trial is genuine. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn is_local_trial_genuine() -> Result<LexActivatorStatus, LexActivatorError> {
     let status = unsafe { IsLocalTrialGenuine() };
     match status {
@@ -2297,7 +2133,6 @@ pub fn is_local_trial_genuine() -> Result<LexActivatorStatus, LexActivatorError>
 /// # Returns
 /// 
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the local trial was extended successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn extend_local_trial(trial_extension_length: u32) -> Result<LexActivatorStatus, LexActivatorError> {
     let c_trial_extension_length: c_uint = trial_extension_length as c_uint;
     let status = unsafe { ExtendLocalTrial(c_trial_extension_length) };
@@ -2318,7 +2153,6 @@ pub fn extend_local_trial(trial_extension_length: u32) -> Result<LexActivatorSta
 /// # Returns
 /// 
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the meter attribute uses was incremented successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn increment_activation_meter_attribute_uses(name: String, increment: u32) -> Result<(), LexActivatorError> {
     let status: i32;
     let c_increment: c_uint = increment as c_uint;
@@ -2335,7 +2169,7 @@ pub fn increment_activation_meter_attribute_uses(name: String, increment: u32) -
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -2349,7 +2183,6 @@ pub fn increment_activation_meter_attribute_uses(name: String, increment: u32) -
 /// # Returns
 /// 
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the meter attribute uses was decremented successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn decrement_activation_meter_attribute_uses(name: String, decrement: u32) -> Result<(), LexActivatorError> {
     let status: i32;
     let c_decrement: c_uint = decrement as c_uint;
@@ -2366,7 +2199,7 @@ pub fn decrement_activation_meter_attribute_uses(name: String, decrement: u32) -
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -2379,7 +2212,6 @@ pub fn decrement_activation_meter_attribute_uses(name: String, decrement: u32) -
 /// # Returns
 /// 
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if the meter attribute uses was reset successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned. 
-
 pub fn reset_activation_meter_attribute_uses(name: String) -> Result<(), LexActivatorError> {
     let status: i32;
     #[cfg(windows)]
@@ -2395,7 +2227,7 @@ pub fn reset_activation_meter_attribute_uses(name: String) -> Result<(), LexActi
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -2412,14 +2244,13 @@ pub fn reset_activation_meter_attribute_uses(name: String) -> Result<(), LexActi
 /// # Returns
 ///
 /// Returns `Ok(LexActivatorStatus)` with the status code `LexActivatorStatus::LA_OK` if migration succeeds, If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn migrate_to_system_wide_activation(old_permission_flag: PermissionFlags) -> Result<LexActivatorStatus, LexActivatorError> {
     let c_old_permission_flag: c_uint = old_permission_flag as c_uint;
     let status = unsafe { MigrateToSystemWideActivation(c_old_permission_flag) };
     if status == 0 {
         Ok(LexActivatorStatus::LA_OK)
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
 
@@ -2432,12 +2263,11 @@ pub fn migrate_to_system_wide_activation(old_permission_flag: PermissionFlags) -
 /// # Returns
 /// 
 /// Returns `Ok(())` if the activation and trial data was reset successfully. If an error occurs, an `Err` containing the `LexActivatorError`is returned.
-
 pub fn reset() -> Result<(), LexActivatorError> {
     let status = unsafe { Reset() };
     if status == 0 {
         Ok(())
     } else {
-        return Err(LexActivatorError::from(status));
+        Err(LexActivatorError::from(status))
     }
 }
@@ -0,0 +1,381 @@
+//! Durable queue for activation meter attribute uses recorded while offline.
+//!
+//! [`increment_activation_meter_attribute_uses`] and [`decrement_activation_meter_attribute_uses`] contact the
+//! Cryptlex servers and fail with `LA_E_INET` while the machine is offline. [`MeterQueue`] records such
+//! operations in a file in the data directory and replays them in order once the servers can be reached
+//! again. Consecutive increments, or consecutive decrements, of the same meter attribute are merged into one.
+//!
+//! An operation rejected with `LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED` during the replay is split and its
+//! parts are retried, so that only the uses beyond the limit are dropped from the queue. The dropped uses are
+//! returned in the [`MeterReplayReport`]. As a consequence, a single increment may be applied in part.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! let meters = MeterQueue::open("/var/lib/my-app")?;
+//! let _replay = meters.replay_on_sync(|report| {
+//!     if let Ok(report) = report {
+//!         for (operation, error) in report.dropped {
+//!             log::warn!("Dropped {:?}: {}", operation, error);
+//!         }
+//!     }
+//! })?;
+//!
+//! meters.increment("exports", 1)?;
+//! ```
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// Name of the queue file in the data directory.
+pub const METER_QUEUE_FILE_NAME: &str = "lexactivator-meter-queue.json";
+
+/// Whether a queued operation increments or decrements the uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeterOperationKind {
+    Increment,
+    Decrement,
+}
+
+/// An increment or decrement of the uses of a meter attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeterOperation {
+    /// The name of the meter attribute.
+    pub name: String,
+    /// Whether the uses are incremented or decremented.
+    pub kind: MeterOperationKind,
+    /// The number of uses.
+    pub uses: u32,
+}
+
+impl MeterOperation {
+    /// Merges `other` into the operation. Returns `false` if the operations cannot be merged.
+    ///
+    /// Only operations of the same kind are merged, as netting an increment against a decrement would change
+    /// which uses reach the servers.
+    fn merge(&mut self, other: &MeterOperation) -> bool {
+        if self.name != other.name || self.kind != other.kind {
+            return false;
+        }
+        let Some(uses) = self.uses.checked_add(other.uses) else {
+            return false;
+        };
+        self.uses = uses;
+        true
+    }
+
+    /// Splits the operation into two halves, or returns `None` if it has a single use.
+    fn split(&self) -> Option<(MeterOperation, MeterOperation)> {
+        if self.uses < 2 {
+            return None;
+        }
+        let first = MeterOperation { uses: self.uses.div_ceil(2), ..self.clone() };
+        let second = MeterOperation { uses: self.uses / 2, ..self.clone() };
+        Some((first, second))
+    }
+
+    fn apply<B: LicensingBackend>(&self, backend: &B) -> Result<(), LexActivatorError> {
+        match self.kind {
+            MeterOperationKind::Increment => backend.increment_activation_meter_attribute_uses(self.name.clone(), self.uses),
+            MeterOperationKind::Decrement => backend.decrement_activation_meter_attribute_uses(self.name.clone(), self.uses),
+        }
+    }
+}
+
+/// The result of [`MeterQueue::replay`].
+#[derive(Debug, Clone, Default)]
+pub struct MeterReplayReport {
+    /// The operations that were sent to the Cryptlex servers.
+    pub replayed: Vec<MeterOperation>,
+    /// The operations that were rejected and removed from the queue, with the error, e.g.
+    /// `LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED`. Only the uses beyond the limit are dropped.
+    pub dropped: Vec<(MeterOperation, LexActivatorError)>,
+    /// The number of operations still in the queue because the servers could not be reached.
+    pub pending: usize,
+}
+
+/// Error returned by [`MeterQueue`].
+#[derive(Debug)]
+pub enum MeterQueueError {
+    /// The queue file could not be read or written.
+    StateFile(StateFileError),
+    /// The LexActivator library returned an error that is neither retryable nor caused by the operation.
+    LexActivator(LexActivatorError),
+}
+
+impl fmt::Display for MeterQueueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeterQueueError::StateFile(error) => write!(f, "{}", error),
+            MeterQueueError::LexActivator(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for MeterQueueError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MeterQueueError::StateFile(error) => Some(error),
+            MeterQueueError::LexActivator(error) => Some(error),
+        }
+    }
+}
+
+impl From<StateFileError> for MeterQueueError {
+    fn from(error: StateFileError) -> Self {
+        MeterQueueError::StateFile(error)
+    }
+}
+
+impl From<LexActivatorError> for MeterQueueError {
+    fn from(error: LexActivatorError) -> Self {
+        MeterQueueError::LexActivator(error)
+    }
+}
+
+/// Errors that reject the operation itself, so that it is dropped instead of retried.
+fn is_rejection(error: &LexActivatorError) -> bool {
+    matches!(
+        error,
+        LexActivatorError::LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED | LexActivatorError::LA_E_METER_ATTRIBUTE_NOT_FOUND
+    )
+}
+
+#[derive(Debug)]
+struct Shared<B: LicensingBackend> {
+    lexactivator: LexActivator<B>,
+    path: PathBuf,
+    operations: Mutex<Vec<MeterOperation>>,
+    // Held for a whole replay, so that two replays do not send the same operation.
+    replay: Mutex<()>,
+}
+
+impl<B: LicensingBackend> Shared<B> {
+    fn lock(&self) -> MutexGuard<'_, Vec<MeterOperation>> {
+        self.operations.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn save(&self, operations: &[MeterOperation]) -> Result<(), MeterQueueError> {
+        if operations.is_empty() {
+            state_file::remove(&self.path)?;
+        } else {
+            state_file::write(&self.path, operations)?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the queued operations, if the new ones can be saved.
+    fn update(&self, update: impl FnOnce(&mut Vec<MeterOperation>)) -> Result<(), MeterQueueError> {
+        let mut operations = self.lock();
+        let mut updated = operations.clone();
+        update(&mut updated);
+        self.save(&updated)?;
+        *operations = updated;
+        Ok(())
+    }
+
+    /// Puts operations back at the front of the queue. They are kept in memory even if they cannot be saved,
+    /// so that they are still replayed by this process.
+    fn requeue(&self, front: impl IntoIterator<Item = MeterOperation>) -> Result<(), MeterQueueError> {
+        let mut operations = self.lock();
+        operations.splice(0..0, front);
+        self.save(&operations)
+    }
+
+    fn record(&self, operation: MeterOperation) -> Result<(), MeterQueueError> {
+        // The lock is not held while the operation is sent, so that a slow server does not block the queue.
+        if self.lock().is_empty() {
+            match operation.apply(self.lexactivator.backend()) {
+                Err(error) if error.is_retryable() => {}
+                result => return result.map_err(MeterQueueError::from),
+            }
+        }
+        self.update(|operations| {
+            let merged = operations.last_mut().is_some_and(|last| last.merge(&operation));
+            if !merged {
+                operations.push(operation);
+            }
+        })
+    }
+
+    fn replay(&self) -> Result<MeterReplayReport, MeterQueueError> {
+        let _replay = self.replay.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut report = MeterReplayReport::default();
+        loop {
+            // The operation is removed from the file before it is sent and put back if it is not applied, so
+            // that a failed save never sends it twice.
+            let mut next = None;
+            self.update(|operations| {
+                if !operations.is_empty() {
+                    next = Some(operations.remove(0));
+                }
+            })?;
+            let Some(operation) = next else {
+                break;
+            };
+            match operation.apply(self.lexactivator.backend()) {
+                Ok(()) => report.replayed.push(operation),
+                Err(error) if error.is_retryable() => {
+                    self.requeue([operation])?;
+                    break;
+                }
+                Err(error) if is_rejection(&error) => {
+                    // Merged operations may exceed the limit while a part of them does not, so the halves
+                    // are retried until only the uses beyond the limit are rejected.
+                    if error == LexActivatorError::LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED {
+                        if let Some((first, second)) = operation.split() {
+                            self.requeue([first, second])?;
+                            continue;
+                        }
+                    }
+                    let merged = report
+                        .dropped
+                        .last_mut()
+                        .is_some_and(|(dropped, dropped_error)| *dropped_error == error && dropped.merge(&operation));
+                    if !merged {
+                        report.dropped.push((operation, error));
+                    }
+                }
+                Err(error) => {
+                    self.requeue([operation])?;
+                    report.pending = self.lock().len();
+                    return Err(error.into());
+                }
+            }
+        }
+        report.pending = self.lock().len();
+        Ok(report)
+    }
+}
+
+/// Queue of activation meter attribute operations, persisted in the data directory.
+///
+/// The queue is cheaply cloneable; clones share the same operations. Open the queue of a data directory only
+/// once per process and clone it, as separately opened queues do not see each other's operations.
+#[derive(Debug)]
+pub struct MeterQueue<B: LicensingBackend = NativeBackend> {
+    shared: Arc<Shared<B>>,
+}
+
+impl<B: LicensingBackend> Clone for MeterQueue<B> {
+    fn clone(&self) -> Self {
+        MeterQueue { shared: Arc::clone(&self.shared) }
+    }
+}
+
+impl MeterQueue {
+    /// Opens the queue stored in the given data directory, usually the directory passed to [`set_data_directory`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(MeterQueue)` with the operations recorded so far. If the queue file cannot be read, an `Err` containing the `MeterQueueError` is returned.
+    pub fn open(data_directory: impl AsRef<Path>) -> Result<Self, MeterQueueError> {
+        MeterQueue::open_with(data_directory, LexActivator::new())
+    }
+}
+
+impl<B: LicensingBackend> MeterQueue<B> {
+    /// Same as [`MeterQueue::open`], sending the operations to the given handle.
+    pub fn open_with(data_directory: impl AsRef<Path>, lexactivator: LexActivator<B>) -> Result<Self, MeterQueueError> {
+        let path = data_directory.as_ref().join(METER_QUEUE_FILE_NAME);
+        let operations = state_file::read(&path)?.unwrap_or_default();
+        Ok(MeterQueue {
+            shared: Arc::new(Shared { lexactivator, path, operations: Mutex::new(operations), replay: Mutex::new(()) }),
+        })
+    }
+
+    /// Increments the meter attribute uses of the activation.
+    ///
+    /// If the queue is empty, the uses are sent directly. Otherwise, or if the Cryptlex servers cannot be
+    /// reached, the operation is queued behind the pending ones. Operations recorded by several threads at the
+    /// same time may be sent in any order.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the meter attribute.
+    /// * `increment` - The number of uses to increment.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the uses are sent or queued, If an error occurs, an `Err` containing the `MeterQueueError` is returned.
+    pub fn increment(&self, name: impl Into<String>, increment: u32) -> Result<(), MeterQueueError> {
+        self.shared.record(MeterOperation { name: name.into(), kind: MeterOperationKind::Increment, uses: increment })
+    }
+
+    /// Decrements the meter attribute uses of the activation. Queued like [`MeterQueue::increment`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the meter attribute.
+    /// * `decrement` - The number of uses to decrement.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the uses are sent or queued, If an error occurs, an `Err` containing the `MeterQueueError` is returned.
+    pub fn decrement(&self, name: impl Into<String>, decrement: u32) -> Result<(), MeterQueueError> {
+        self.shared.record(MeterOperation { name: name.into(), kind: MeterOperationKind::Decrement, uses: decrement })
+    }
+
+    /// Sends the queued operations in order, until the queue is empty or the Cryptlex servers cannot be reached.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(MeterReplayReport)` with the replayed, dropped and pending operations. If the LexActivator library returns an error that does not concern the operation, e.g. because the license is not activated, the replay stops and an `Err` containing the `MeterQueueError` is returned.
+    pub fn replay(&self) -> Result<MeterReplayReport, MeterQueueError> {
+        self.shared.replay()
+    }
+
+    /// Returns the handle the operations are sent to.
+    pub(crate) fn lexactivator(&self) -> &LexActivator<B> {
+        &self.shared.lexactivator
+    }
+
+    /// Returns the queued operations.
+    pub fn pending(&self) -> Vec<MeterOperation> {
+        self.shared.lock().clone()
+    }
+
+    /// Returns `true` if no operations are queued.
+    pub fn is_empty(&self) -> bool {
+        self.shared.lock().is_empty()
+    }
+}
+
+impl<B: LicensingBackend + Send + Sync + 'static> MeterQueue<B> {
+    /// Replays the queue on a background thread whenever the license callback reports a successful server sync.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_replay` - The closure to be called with the result of each replay e.g. |report| { println!("{:?}", report) }
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(LicenseCallbackSubscription)` if the queue is subscribed successfully, If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+    pub fn replay_on_sync<F>(&self, on_replay: F) -> Result<LicenseCallbackSubscription, LexActivatorError>
+    where
+        F: Fn(Result<MeterReplayReport, MeterQueueError>) + Send + Sync + 'static,
+    {
+        let weak = Arc::downgrade(&self.shared);
+        let on_replay = Arc::new(on_replay);
+        subscribe_license_events(move |event| {
+            if event != LicenseEvent::Valid {
+                return;
+            }
+            let Some(shared) = weak.upgrade() else {
+                return;
+            };
+            if shared.lock().is_empty() {
+                return;
+            }
+            // The LexActivator library is not called from its own callback thread.
+            let on_replay = Arc::clone(&on_replay);
+            std::thread::spawn(move || on_replay(shared.replay()));
+        })
+    }
+}
@@ -0,0 +1,156 @@
+//! Quota checks for meter attributes.
+//!
+//! [`MeterQuota`] combines the uses of a meter attribute across all activations of the license, as returned by
+//! [`get_license_meterattribute`], with the uses of this activation, and interprets -1 allowed uses as
+//! unlimited. The total uses include the uses of this activation as of the last server sync; the uses of this
+//! activation are a lower bound of the total, so that a quota is never reported as less used than this
+//! activation alone uses it. [`get_meter_quotas`] reads several attributes at once, so that an operation can
+//! be blocked before it fails with `LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED`.
+//!
+//! The uses are read from the local activation data and are only as recent as the last server sync.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! let quotas = get_meter_quotas(["exports", "api_calls"])?;
+//! if quotas.iter().any(|quota| quota.would_exceed(1)) {
+//!     return Err("Quota exceeded".into());
+//! }
+//! ```
+
+use std::sync::PoisonError;
+
+use crate::*;
+
+/// The allowed and used uses of a meter attribute.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeterQuota {
+    /// The name of the meter attribute.
+    pub name: String,
+    /// The number of allowed uses. -1 means unlimited.
+    pub allowed_uses: i64,
+    /// The total number of uses of all activations of the license.
+    pub total_uses: u64,
+    /// The gross number of uses of all activations of the license.
+    pub gross_uses: u64,
+    /// The number of uses of this activation.
+    pub activation_uses: u32,
+}
+
+impl MeterQuota {
+    /// Creates the quota from the license meter attribute and the uses of this activation.
+    pub fn new(attribute: LicenseMeterAttribute, activation_uses: u32) -> Self {
+        MeterQuota {
+            name: attribute.name,
+            allowed_uses: attribute.allowed_uses,
+            total_uses: attribute.total_uses,
+            gross_uses: attribute.gross_uses,
+            activation_uses,
+        }
+    }
+
+    /// Reads the quota of the meter attribute from the given backend.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(MeterQuota)` with the uses of the meter attribute. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+    pub fn read<B: LicensingBackend>(backend: &B, name: impl Into<String>) -> Result<Self, LexActivatorError> {
+        let name = name.into();
+        let attribute = backend.get_license_meterattribute(name.clone())?;
+        let activation_uses = backend.get_activation_meter_attribute_uses(name)?;
+        Ok(MeterQuota::new(attribute, activation_uses))
+    }
+
+    /// Returns the uses counted against the allowed uses: the total uses of all activations, but at least the
+    /// uses of this activation.
+    pub fn used(&self) -> u64 {
+        self.total_uses.max(u64::from(self.activation_uses))
+    }
+
+    /// Returns `true` if the meter attribute has unlimited uses.
+    pub fn is_unlimited(&self) -> bool {
+        self.allowed_uses < 0
+    }
+
+    /// Returns the number of uses left, or `None` if the uses are unlimited.
+    pub fn remaining(&self) -> Option<u64> {
+        if self.is_unlimited() {
+            return None;
+        }
+        Some((self.allowed_uses as u64).saturating_sub(self.used()))
+    }
+
+    /// Returns `true` if incrementing the uses by `uses` would exceed the allowed uses.
+    pub fn would_exceed(&self, uses: u32) -> bool {
+        self.remaining().is_some_and(|remaining| u64::from(uses) > remaining)
+    }
+
+    /// Returns the share of the allowed uses that is used, from 0.0 upwards, or `None` if the uses are
+    /// unlimited. A meter attribute without allowed uses is fully used.
+    pub fn utilisation(&self) -> Option<f64> {
+        match self.allowed_uses {
+            allowed_uses if allowed_uses < 0 => None,
+            0 => Some(1.0),
+            allowed_uses => Some(self.used() as f64 / allowed_uses as f64),
+        }
+    }
+}
+
+/// Reads the quotas of the meter attributes under the lock of the composite operations of this crate, so that
+/// the reads do not interleave with a license snapshot or a watchdog check.
+fn read_quotas<B, I, S>(backend: &B, names: I) -> Result<Vec<MeterQuota>, LexActivatorError>
+where
+    B: LicensingBackend,
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let _guard = API_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    names.into_iter().map(|name| MeterQuota::read(backend, name)).collect()
+}
+
+impl<B: LicensingBackend> LexActivator<B> {
+    /// See [`get_meter_quota`].
+    pub fn meter_quota(&self, name: impl Into<String>) -> Result<MeterQuota, LexActivatorError> {
+        MeterQuota::read(self.backend(), name)
+    }
+
+    /// See [`get_meter_quotas`].
+    pub fn meter_quotas<I, S>(&self, names: I) -> Result<Vec<MeterQuota>, LexActivatorError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        read_quotas(self.backend(), names)
+    }
+}
+
+/// Retrieves the quota of the meter attribute.
+///
+/// # Arguments
+///
+/// * `name` - The name of the meter attribute.
+///
+/// # Returns
+///
+/// Returns `Ok(MeterQuota)` with the uses of the meter attribute. If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_meter_quota(name: impl Into<String>) -> Result<MeterQuota, LexActivatorError> {
+    MeterQuota::read(&NativeBackend, name)
+}
+
+/// Retrieves the quotas of several meter attributes at once.
+///
+/// # Arguments
+///
+/// * `names` - The names of the meter attributes.
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<MeterQuota>)` with the quotas in the order of the names. If an error occurs for any of them, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_meter_quotas<I, S>(names: I) -> Result<Vec<MeterQuota>, LexActivatorError>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    read_quotas(&NativeBackend, names)
+}
@@ -0,0 +1,162 @@
+//! Scoped uses of activation meter attributes.
+//!
+//! [`MeterUsageGuard`] increments the uses of a meter attribute when it is acquired and decrements them again
+//! when it is dropped, on every exit path including early returns and panics. This suits concurrent-seat style
+//! meters such as `active_sessions`.
+//!
+//! For consumption meters, where a use is spent once the work is done, call [`MeterUsageGuard::commit`] after
+//! the work succeeded. A guard that is dropped without being committed returns the uses.
+//!
+//! Returning the uses contacts the Cryptlex servers and can fail, e.g. with `LA_E_INET` while offline. A guard
+//! acquired with [`MeterUsageGuard::acquire_queued`] returns the uses through a [`MeterQueue`], which keeps
+//! them until the servers can be reached. Errors that the queue cannot handle, and all errors of a guard that
+//! returns the uses directly, are passed to the closure set with [`MeterUsageGuard::on_release_error`].
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! let meters = MeterQueue::open("/var/lib/my-app")?;
+//! let session = MeterUsageGuard::acquire_queued(&meters, "active_sessions", 1)?
+//!     .on_release_error(|operation, error| log::error!("Failed to return {:?}: {}", operation, error));
+//! run_session()?;
+//! drop(session);
+//!
+//! let export = MeterUsageGuard::acquire("exports", 1)?;
+//! write_export()?;
+//! export.commit();
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::*;
+
+type ReleaseErrorHandler = Arc<dyn Fn(MeterOperation, MeterQueueError) + Send + Sync + 'static>;
+
+/// How the uses of a guard are returned.
+enum Release<B: LicensingBackend> {
+    Direct,
+    Queued(MeterQueue<B>),
+}
+
+/// Uses of a meter attribute that are returned when the guard is dropped, created by [`MeterUsageGuard::acquire`].
+#[must_use = "the uses are decremented again when the guard is dropped"]
+pub struct MeterUsageGuard<B: LicensingBackend = NativeBackend> {
+    lexactivator: LexActivator<B>,
+    name: String,
+    uses: u32,
+    release: Release<B>,
+    on_release_error: Option<ReleaseErrorHandler>,
+    // Set once the uses are committed or released, so that they are not decremented again on drop.
+    finished: bool,
+}
+
+impl<B: LicensingBackend> fmt::Debug for MeterUsageGuard<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeterUsageGuard")
+            .field("name", &self.name)
+            .field("uses", &self.uses)
+            .field("queued", &matches!(self.release, Release::Queued(_)))
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MeterUsageGuard {
+    /// Increments the meter attribute uses of the activation, unless they would exceed the allowed uses.
+    ///
+    /// The allowed uses are checked against the local activation data before the uses are incremented. The
+    /// check and the increment are separate calls and are not atomic: another thread or activation may use the
+    /// remaining uses in between, in which case the Cryptlex servers reject the increment and this function
+    /// returns `LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED` as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the meter attribute.
+    /// * `uses` - The number of uses to increment.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(MeterUsageGuard)` if the uses are incremented successfully. If the allowed uses of the license are already used, an `Err` containing `LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED` is returned. If another error occurs, an `Err` containing the `LexActivatorError` is returned.
+    pub fn acquire(name: impl Into<String>, uses: u32) -> Result<Self, LexActivatorError> {
+        MeterUsageGuard::acquire_with(LexActivator::new(), name, uses)
+    }
+}
+
+impl<B: LicensingBackend> MeterUsageGuard<B> {
+    /// Same as [`MeterUsageGuard::acquire`], using the given handle.
+    pub fn acquire_with(lexactivator: LexActivator<B>, name: impl Into<String>, uses: u32) -> Result<Self, LexActivatorError> {
+        MeterUsageGuard::increment(lexactivator, name.into(), uses, Release::Direct)
+    }
+
+    /// Same as [`MeterUsageGuard::acquire`], returning the uses through the queue, so that they are returned
+    /// once the Cryptlex servers can be reached if the guard is dropped while offline.
+    pub fn acquire_queued(queue: &MeterQueue<B>, name: impl Into<String>, uses: u32) -> Result<Self, LexActivatorError> {
+        MeterUsageGuard::increment(queue.lexactivator().clone(), name.into(), uses, Release::Queued(queue.clone()))
+    }
+
+    fn increment(lexactivator: LexActivator<B>, name: String, uses: u32, release: Release<B>) -> Result<Self, LexActivatorError> {
+        let attribute = lexactivator.get_license_meterattribute(name.clone())?;
+        if MeterQuota::new(attribute, 0).would_exceed(uses) {
+            return Err(LexActivatorError::LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED);
+        }
+        lexactivator.increment_activation_meter_attribute_uses(name.clone(), uses)?;
+        Ok(MeterUsageGuard { lexactivator, name, uses, release, on_release_error: None, finished: false })
+    }
+
+    /// Sets a closure that is called with the operation and the error if the uses cannot be returned when the
+    /// guard is dropped. Without it such errors are ignored.
+    pub fn on_release_error<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(MeterOperation, MeterQueueError) + Send + Sync + 'static,
+    {
+        self.on_release_error = Some(Arc::new(handler));
+        self
+    }
+
+    /// Returns the name of the meter attribute.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of uses held by the guard.
+    pub fn uses(&self) -> u32 {
+        self.uses
+    }
+
+    /// Keeps the uses instead of decrementing them on drop, for consumption meters.
+    pub fn commit(mut self) {
+        self.finished = true;
+    }
+
+    /// Decrements the uses now instead of on drop.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the uses are decremented, or queued by the [`MeterQueue`] of the guard. If an error occurs, an `Err` containing the `MeterQueueError` is returned; the uses are not decremented again on drop.
+    pub fn release(mut self) -> Result<(), MeterQueueError> {
+        self.finished = true;
+        self.decrement()
+    }
+
+    fn decrement(&self) -> Result<(), MeterQueueError> {
+        match &self.release {
+            Release::Direct => Ok(self.lexactivator.decrement_activation_meter_attribute_uses(self.name.clone(), self.uses)?),
+            Release::Queued(queue) => queue.decrement(self.name.clone(), self.uses),
+        }
+    }
+}
+
+impl<B: LicensingBackend> Drop for MeterUsageGuard<B> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        if let Err(error) = self.decrement() {
+            if let Some(handler) = &self.on_release_error {
+                let operation = MeterOperation { name: self.name.clone(), kind: MeterOperationKind::Decrement, uses: self.uses };
+                events::call_guarded(|| handler(operation, error));
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Async versions of the functions that block on the LexActivator library, enabled by the `tokio` feature.
+//!
+//! Activation, validation and authentication contact the Cryptlex servers from inside the native library
+//! and can block the calling thread for several seconds. The functions of this module queue the matching
+//! function of the crate to a dedicated worker thread and return the same result, so waiting callers do not
+//! hold any thread of the async runtime.
+//!
+//! The worker runs the queued calls one at a time, in the order they are made, so the async functions never
+//! run concurrently with each other. Each call also holds the lock of the composite operations of the crate,
+//! such as reading a [`LicenseSnapshot`] or checking a [`LicenseWatchdog`], so these see the state before or
+//! after the call. Single blocking functions of the crate called from other threads are not serialised with
+//! the async functions. The futures do not depend on a Tokio runtime.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! let status = nonblocking::activate_license().await?;
+//!
+//! let mut codes = nonblocking::license_callback_stream()?;
+//! while let Some(code) = std::future::poll_fn(|cx| Pin::new(&mut codes).poll_next(cx)).await {
+//!     println!("License callback: {:?}", code);
+//! }
+//! ```
+
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc as std_mpsc;
+use std::sync::{LazyLock, PoisonError};
+use std::task::{Context, Poll};
+use std::thread;
+
+use futures_core::Stream;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::*;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Queue of the worker thread that runs the calls of the async functions, or `None` if it could not be spawned.
+static WORKER: LazyLock<Option<std_mpsc::Sender<Job>>> = LazyLock::new(|| {
+    let (sender, receiver) = std_mpsc::channel::<Job>();
+    let spawned = thread::Builder::new().name("lexactivator-nonblocking".to_string()).spawn(move || {
+        for job in receiver {
+            job();
+        }
+    });
+    spawned.ok().map(|_| sender)
+});
+
+async fn run_blocking<T, F>(function: F) -> Result<T, LexActivatorError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, LexActivatorError> + Send + 'static,
+{
+    let (sender, receiver) = oneshot::channel();
+    let job: Job = Box::new(move || {
+        let _guard = API_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        // The panic is resumed on the caller, so that it does not stop the worker.
+        let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(function)));
+    });
+    let queued = WORKER.as_ref().is_some_and(|worker| worker.send(job).is_ok());
+    if !queued {
+        return Err(LexActivatorError::LA_FAIL);
+    }
+    match receiver.await {
+        Ok(Ok(result)) => result,
+        Ok(Err(payload)) => panic::resume_unwind(payload),
+        Err(_) => Err(LexActivatorError::LA_FAIL),
+    }
+}
+
+// ------------------- Getter Functions --------------------
+
+/// See [`crate::get_user_licenses`].
+pub async fn get_user_licenses() -> Result<Vec<UserLicense>, LexActivatorError> {
+    run_blocking(crate::get_user_licenses).await
+}
+
+// ------------------ Action Functions ------------------
+
+/// See [`crate::authenticate_user`].
+pub async fn authenticate_user(email: String, password: String) -> Result<(), LexActivatorError> {
+    run_blocking(move || crate::authenticate_user(email, password)).await
+}
+
+/// See [`crate::authenticate_user_with_id_token`].
+pub async fn authenticate_user_with_id_token(id_token: String) -> Result<(), LexActivatorError> {
+    run_blocking(move || crate::authenticate_user_with_id_token(id_token)).await
+}
+
+/// See [`crate::activate_license`].
+pub async fn activate_license() -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(crate::activate_license).await
+}
+
+/// See [`crate::activate_license_offline`].
+pub async fn activate_license_offline(file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(move || crate::activate_license_offline(file_path)).await
+}
+
+/// See [`crate::generate_offline_activation_request`].
+pub async fn generate_offline_activation_request(file_path: String) -> Result<(), LexActivatorError> {
+    run_blocking(move || crate::generate_offline_activation_request(file_path)).await
+}
+
+/// See [`crate::deactivate_license`].
+pub async fn deactivate_license() -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(crate::deactivate_license).await
+}
+
+/// See [`crate::generate_offline_deactivation_request`].
+pub async fn generate_offline_deactivation_request(file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(move || crate::generate_offline_deactivation_request(file_path)).await
+}
+
+/// See [`crate::is_license_genuine`].
+pub async fn is_license_genuine() -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(crate::is_license_genuine).await
+}
+
+/// See [`crate::is_license_valid`].
+pub async fn is_license_valid() -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(crate::is_license_valid).await
+}
+
+/// See [`crate::sync_license_activation`].
+pub async fn sync_license_activation() -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(crate::sync_license_activation).await
+}
+
+/// See [`crate::activate_trial`].
+pub async fn activate_trial() -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(crate::activate_trial).await
+}
+
+/// See [`crate::sync_trial_activation`].
+pub async fn sync_trial_activation() -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(crate::sync_trial_activation).await
+}
+
+/// See [`crate::activate_trial_offline`].
+pub async fn activate_trial_offline(file_path: String) -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(move || crate::activate_trial_offline(file_path)).await
+}
+
+/// See [`crate::generate_offline_trial_activation_request`].
+pub async fn generate_offline_trial_activation_request(file_path: String) -> Result<(), LexActivatorError> {
+    run_blocking(move || crate::generate_offline_trial_activation_request(file_path)).await
+}
+
+/// See [`crate::is_trial_genuine`].
+pub async fn is_trial_genuine() -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(crate::is_trial_genuine).await
+}
+
+/// See [`crate::activate_local_trial`].
+pub async fn activate_local_trial(trial_length: u32) -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(move || crate::activate_local_trial(trial_length)).await
+}
+
+/// See [`crate::is_local_trial_genuine`].
+pub async fn is_local_trial_genuine() -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(crate::is_local_trial_genuine).await
+}
+
+/// See [`crate::extend_local_trial`].
+pub async fn extend_local_trial(trial_extension_length: u32) -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(move || crate::extend_local_trial(trial_extension_length)).await
+}
+
+/// See [`crate::increment_activation_meter_attribute_uses`].
+pub async fn increment_activation_meter_attribute_uses(name: String, increment: u32) -> Result<(), LexActivatorError> {
+    run_blocking(move || crate::increment_activation_meter_attribute_uses(name, increment)).await
+}
+
+/// See [`crate::decrement_activation_meter_attribute_uses`].
+pub async fn decrement_activation_meter_attribute_uses(name: String, decrement: u32) -> Result<(), LexActivatorError> {
+    run_blocking(move || crate::decrement_activation_meter_attribute_uses(name, decrement)).await
+}
+
+/// See [`crate::reset_activation_meter_attribute_uses`].
+pub async fn reset_activation_meter_attribute_uses(name: String) -> Result<(), LexActivatorError> {
+    run_blocking(move || crate::reset_activation_meter_attribute_uses(name)).await
+}
+
+/// See [`crate::migrate_to_system_wide_activation`].
+pub async fn migrate_to_system_wide_activation(old_permission_flag: PermissionFlags) -> Result<LexActivatorStatus, LexActivatorError> {
+    run_blocking(move || crate::migrate_to_system_wide_activation(old_permission_flag)).await
+}
+
+/// See [`crate::reset`].
+pub async fn reset() -> Result<(), LexActivatorError> {
+    run_blocking(crate::reset).await
+}
+
+// ------------------ License Callback ------------------
+
+/// Stream of the codes delivered to the license callback, created by [`license_callback_stream`].
+///
+/// Dropping the stream unsubscribes it from the license callback.
+#[derive(Debug)]
+pub struct LicenseCallbackStream {
+    receiver: mpsc::UnboundedReceiver<LexActivatorCode>,
+    _subscription: LicenseCallbackSubscription,
+}
+
+impl Stream for LicenseCallbackStream {
+    type Item = LexActivatorCode;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// Subscribes to the license callback and returns a stream of the codes it receives.
+///
+/// Other license callback subscribers and the callback set by [`crate::set_license_callback`] keep receiving the codes.
+///
+/// # Returns
+///
+/// Returns `Ok(LicenseCallbackStream)` if the stream is subscribed successfully, If an error occurs, an `Err` containing the `LexActivatorError` is returned.
+pub fn license_callback_stream() -> Result<LicenseCallbackStream, LexActivatorError> {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let subscription = crate::subscribe_license_callback(move |code| {
+        let _ = sender.send(code);
+    })?;
+    Ok(LicenseCallbackStream { receiver, _subscription: subscription })
+}
@@ -0,0 +1,64 @@
+//! Background thread that runs a task at a fixed interval until it is stopped.
+
+use std::io;
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Shortest interval of a periodic thread. Shorter intervals are raised to it, so that a zero interval does not
+/// run the task in a busy loop.
+pub(crate) const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Default)]
+struct StopSignal {
+    stopping: Mutex<bool>,
+    wakeup: Condvar,
+}
+
+/// A thread that runs a task after every interval. Dropping it stops the thread and waits for it to finish.
+#[derive(Debug)]
+pub(crate) struct PeriodicThread {
+    signal: Arc<StopSignal>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl PeriodicThread {
+    /// Spawns the thread. The task first runs one interval, at least [`MIN_INTERVAL`], after this function returns.
+    pub(crate) fn spawn<F>(name: &str, interval: Duration, mut task: F) -> io::Result<Self>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let interval = interval.max(MIN_INTERVAL);
+        let signal = Arc::new(StopSignal::default());
+        let thread_signal = Arc::clone(&signal);
+        let thread = thread::Builder::new().name(name.to_string()).spawn(move || loop {
+            let stopping = thread_signal.stopping.lock().unwrap_or_else(PoisonError::into_inner);
+            let (stopping, _) = thread_signal
+                .wakeup
+                .wait_timeout_while(stopping, interval, |stopping| !*stopping)
+                .unwrap_or_else(PoisonError::into_inner);
+            if *stopping {
+                return;
+            }
+            drop(stopping);
+            task();
+        })?;
+        Ok(PeriodicThread { signal, thread: Some(thread) })
+    }
+
+    /// Stops the thread and waits for a running task to finish.
+    pub(crate) fn stop(&mut self) {
+        *self.signal.stopping.lock().unwrap_or_else(PoisonError::into_inner) = true;
+        self.signal.wakeup.notify_all();
+        if let Some(thread) = self.thread.take() {
+            // The tasks catch the panics of user callbacks, so the thread does not panic.
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for PeriodicThread {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1,175 @@
+//! All license attributes in one call.
+//!
+//! [`get_license_snapshot`] reads every attribute of the activated license in one go. It holds a lock that
+//! the other composite operations of this crate, such as the license watchdog and the meter quota reader,
+//! also take, so their calls do not interleave with the snapshot. Calls of the plain functions of the crate
+//! from other threads are not serialised with it. Attributes that do not apply to the license, e.g. the
+//! entitlement set of a license without one, are `None`, as are the attributes of a license or trial that
+//! is not activated; any other error, including `LA_FAIL`, is returned. With the `serde` feature the
+//! snapshot can be serialized, e.g. to send it to support tooling.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! let snapshot = get_license_snapshot()?;
+//! println!("{}", serde_json::to_string_pretty(&snapshot)?);
+//! ```
+
+use crate::*;
+
+/// The attributes of the license and its activation at one point in time.
+///
+/// Dates are in seconds since the Unix epoch. A date that is not set, e.g. the expiry date of a license that
+/// never expires, is `None`.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LicenseSnapshot {
+    /// The license key.
+    pub license_key: Option<String>,
+    /// The license type, `node-locked`, `hosted-floating` or `on-premise-floating`.
+    pub license_type: Option<String>,
+    /// The allowed activations count of the license. -1 means unlimited.
+    pub allowed_activations: Option<i64>,
+    /// Total number of activations of the license.
+    pub total_activations: Option<u32>,
+    /// The allowed deactivations count of the license. -1 means unlimited.
+    pub allowed_deactivations: Option<i64>,
+    /// Total number of deactivations of the license.
+    pub total_deactivations: Option<u32>,
+    /// The license creation date.
+    pub creation_date: Option<u32>,
+    /// The license activation date.
+    pub activation_date: Option<u32>,
+    /// The license expiry date.
+    pub expiry_date: Option<u32>,
+    /// The license maintenance expiry date.
+    pub maintenance_expiry_date: Option<u32>,
+    /// The maximum allowed release version.
+    pub max_allowed_release_version: Option<String>,
+    /// The email of the license user.
+    pub user_email: Option<String>,
+    /// The name of the license user.
+    pub user_name: Option<String>,
+    /// The company of the license user.
+    pub user_company: Option<String>,
+    /// The name of the organization of the license.
+    pub organization_name: Option<String>,
+    /// The address of the organization of the license.
+    pub organization_address: Option<OrganizationAddress>,
+    /// The name of the entitlement set linked to the license.
+    pub entitlement_set_name: Option<String>,
+    /// The display name of the entitlement set linked to the license.
+    pub entitlement_set_display_name: Option<String>,
+    /// The tier of the entitlement set linked to the license.
+    pub entitlement_set_tier: Option<i64>,
+    /// The feature entitlements of the license.
+    pub feature_entitlements: Option<Vec<FeatureEntitlement>>,
+    /// The activation ID.
+    pub activation_id: Option<String>,
+    /// The initial and current activation mode.
+    pub activation_mode: Option<ActivationMode>,
+    /// The date of the last successful server sync of the activation.
+    pub activation_last_synced_date: Option<u32>,
+    /// The expiry date of the server sync grace period.
+    pub server_sync_grace_period_expiry_date: Option<u32>,
+    /// The trial ID.
+    pub trial_id: Option<String>,
+    /// The trial expiry date.
+    pub trial_expiry_date: Option<u32>,
+    /// The local trial expiry date.
+    pub local_trial_expiry_date: Option<u32>,
+    /// The version of the LexActivator library.
+    pub library_version: Option<String>,
+}
+
+/// Errors that mean that the attribute does not apply to the license, as opposed to a failure to read it.
+fn is_not_applicable(error: &LexActivatorError) -> bool {
+    matches!(
+        error,
+        LexActivatorError::LA_E_ENTITLEMENT_SET_NOT_LINKED
+            | LexActivatorError::LA_E_PRODUCT_VERSION_NOT_LINKED
+    )
+}
+
+/// Whether the license or trial checked by a status function is activated. The status functions return
+/// `LA_FAIL` as the status of a license or trial that is not activated.
+fn is_activated(status: Result<LexActivatorStatus, LexActivatorError>) -> Result<bool, LexActivatorError> {
+    Ok(status? != LexActivatorStatus::LA_FAIL)
+}
+
+fn attribute<T>(result: Result<T, LexActivatorError>) -> Result<Option<T>, LexActivatorError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if is_not_applicable(&error) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+fn date(result: Result<u32, LexActivatorError>) -> Result<Option<u32>, LexActivatorError> {
+    Ok(attribute(result)?.filter(|date| *date != 0))
+}
+
+impl LicenseSnapshot {
+    /// Reads the license attributes from the given backend.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(LicenseSnapshot)` with the license attributes. If an attribute cannot be read, e.g. because the product is not set up or the JSON returned by the library cannot be parsed, an `Err` containing the `LexActivatorError` is returned.
+    pub fn capture<B: LicensingBackend>(backend: &B) -> Result<Self, LexActivatorError> {
+        let _guard = API_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut snapshot = LicenseSnapshot::default();
+        if is_activated(backend.is_license_valid())? {
+            snapshot.license_key = attribute(backend.get_license_key())?;
+            snapshot.license_type = attribute(backend.get_license_type())?;
+            snapshot.allowed_activations = attribute(backend.get_license_allowed_activations())?;
+            snapshot.total_activations = attribute(backend.get_license_total_activations())?;
+            snapshot.allowed_deactivations = attribute(backend.get_license_allowed_deactivations())?;
+            snapshot.total_deactivations = attribute(backend.get_license_total_deactivations())?;
+            snapshot.creation_date = date(backend.get_license_creation_date())?;
+            snapshot.activation_date = date(backend.get_license_activation_date())?;
+            snapshot.expiry_date = date(backend.get_license_expiry_date())?;
+            snapshot.maintenance_expiry_date = date(backend.get_license_maintenance_expiry_date())?;
+            snapshot.max_allowed_release_version = attribute(backend.get_license_max_allowed_release_version())?;
+            snapshot.user_email = attribute(backend.get_license_user_email())?;
+            snapshot.user_name = attribute(backend.get_license_user_name())?;
+            snapshot.user_company = attribute(backend.get_license_user_company())?;
+            snapshot.organization_name = attribute(backend.get_license_organization_name())?;
+            snapshot.organization_address = attribute(backend.get_license_organization_address())?;
+            snapshot.entitlement_set_name = attribute(backend.get_license_entitlement_set_name())?;
+            snapshot.entitlement_set_display_name = attribute(backend.get_license_entitlement_set_display_name())?;
+            snapshot.entitlement_set_tier = attribute(backend.get_license_entitlement_set_tier())?;
+            snapshot.feature_entitlements = attribute(backend.get_feature_entitlements())?;
+            snapshot.activation_id = attribute(backend.get_activation_id())?;
+            snapshot.activation_mode = attribute(backend.get_activation_mode())?;
+            snapshot.activation_last_synced_date = date(backend.get_activation_last_synced_date())?;
+            snapshot.server_sync_grace_period_expiry_date = date(backend.get_server_sync_grace_period_expiry_date())?;
+        }
+        if is_activated(backend.is_trial_genuine())? {
+            snapshot.trial_id = attribute(backend.get_trial_id())?;
+            snapshot.trial_expiry_date = date(backend.get_trial_expiry_date())?;
+        }
+        if is_activated(backend.is_local_trial_genuine())? {
+            snapshot.local_trial_expiry_date = date(backend.get_local_trial_expiry_date())?;
+        }
+        snapshot.library_version = attribute(backend.get_library_version())?;
+        Ok(snapshot)
+    }
+}
+
+impl<B: LicensingBackend> LexActivator<B> {
+    /// See [`get_license_snapshot`].
+    pub fn license_snapshot(&self) -> Result<LicenseSnapshot, LexActivatorError> {
+        LicenseSnapshot::capture(self.backend())
+    }
+}
+
+/// Retrieves all attributes of the license and its activation.
+///
+/// Attributes that do not apply to the license are `None` instead of failing the whole snapshot.
+///
+/// # Returns
+///
+/// Returns `Ok(LicenseSnapshot)` with the license attributes. If an attribute cannot be read, e.g. because the product is not set up or the JSON returned by the library cannot be parsed, an `Err` containing the `LexActivatorError` is returned.
+pub fn get_license_snapshot() -> Result<LicenseSnapshot, LexActivatorError> {
+    LicenseSnapshot::capture(&NativeBackend)
+}
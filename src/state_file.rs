@@ -0,0 +1,82 @@
+//! JSON files in the data directory that keep the state of [`MeterQueue`](crate::MeterQueue) and
+//! [`ExpiryWarnings`](crate::ExpiryWarnings) across restarts.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Error returned when a state file in the data directory cannot be read or written.
+#[derive(Debug)]
+pub enum StateFileError {
+    /// The file could not be read or written.
+    Io { path: PathBuf, error: io::Error },
+    /// The file does not contain valid state.
+    Parse { path: PathBuf, error: serde_json::Error },
+}
+
+impl fmt::Display for StateFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateFileError::Io { path, error } => write!(f, "Failed to access {}: {}", path.display(), error),
+            StateFileError::Parse { path, error } => write!(f, "Failed to parse {}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for StateFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StateFileError::Io { error, .. } => Some(error),
+            StateFileError::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Reads a state file, or returns `None` if it does not exist.
+pub(crate) fn read<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, StateFileError> {
+    match fs::read(path) {
+        Ok(json) => serde_json::from_slice(&json).map(Some).map_err(|error| StateFileError::Parse { path: path.to_path_buf(), error }),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(StateFileError::Io { path: path.to_path_buf(), error }),
+    }
+}
+
+/// Replaces a state file, so that it holds either the old or the new state after a crash or power loss.
+pub(crate) fn write<T: Serialize + ?Sized>(path: &Path, state: &T) -> Result<(), StateFileError> {
+    let io_error = |error| StateFileError::Io { path: path.to_path_buf(), error };
+    let json = serde_json::to_vec_pretty(state).map_err(|error| StateFileError::Parse { path: path.to_path_buf(), error })?;
+    // Written to a temporary file first, so that a crash does not leave a truncated file behind.
+    let temporary_path = path.with_extension("json.tmp");
+    let mut file = fs::File::create(&temporary_path).map_err(io_error)?;
+    file.write_all(&json).map_err(io_error)?;
+    // Flushed before the rename, so that the renamed file is never empty after a power loss.
+    file.sync_all().map_err(io_error)?;
+    fs::rename(&temporary_path, path).map_err(io_error)?;
+    sync_directory(path).map_err(io_error)
+}
+
+/// Removes a state file if it exists.
+pub(crate) fn remove(path: &Path) -> Result<(), StateFileError> {
+    match fs::remove_file(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(StateFileError::Io { path: path.to_path_buf(), error }),
+        _ => Ok(()),
+    }
+}
+
+/// Flushes the directory entry of the file, so that a rename survives a power loss.
+#[cfg(unix)]
+fn sync_directory(path: &Path) -> io::Result<()> {
+    match path.parent().filter(|directory| !directory.as_os_str().is_empty()) {
+        Some(directory) => fs::File::open(directory).and_then(|directory| directory.sync_all()),
+        None => Ok(()),
+    }
+}
+
+#[cfg(not(unix))]
+fn sync_directory(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
@@ -1,7 +1,16 @@
-use std::ffi::{ c_char, CStr, CString, NulError};
+use std::ffi::{ c_char, c_int, c_uint, CStr, CString, NulError};
+
+use crate::error_codes::LexActivatorError;
 
 // --------------------------- String operations ------------------------
 
+/// Character type of the strings exchanged with the LexActivator library.
+#[cfg(windows)]
+pub type NativeChar = u16;
+
+#[cfg(not(windows))]
+pub type NativeChar = c_char;
+
 pub fn string_to_cstring(mut rust_string: String) -> Result<CString, NulError> {
     if rust_string.contains('\0') {
         rust_string = rust_string.replace('\0', "");
@@ -10,6 +19,14 @@ pub fn string_to_cstring(mut rust_string: String) -> Result<CString, NulError> {
     Ok(c_string)
 }
 
+/// Returns `true` if the release version is in the format x.x, x.x.x or x.x.x.x.
+pub fn is_valid_release_version(version: &str) -> bool {
+    let parts: Vec<&str> = version.split('.').collect();
+    (2..=4).contains(&parts.len())
+        && parts.iter().all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(windows)]
 pub fn to_utf16(rust_string: String) -> Vec<u16> {
     let utf16: Vec<u16> = rust_string
         .encode_utf16()
@@ -18,9 +35,59 @@ pub fn to_utf16(rust_string: String) -> Vec<u16> {
     utf16
 }
 
+/// Length of the buffer first passed to the LexActivator getters.
+pub const INITIAL_BUFFER_LENGTH: usize = 256;
+
+/// Largest buffer, in characters, the getters allocate before giving up with `LA_E_BUFFER_SIZE`.
+pub const MAX_BUFFER_LENGTH: usize = 1024 * 1024;
+
+/// Calls a LexActivator getter that writes a string into a caller-provided buffer.
+///
+/// The buffer starts at `initial_length` characters and is grown each time the library returns
+/// `LA_E_BUFFER_SIZE`, up to [`MAX_BUFFER_LENGTH`].
+pub fn get_native_string<F>(initial_length: usize, mut getter: F) -> Result<String, LexActivatorError>
+where
+    F: FnMut(*mut NativeChar, c_uint) -> c_int,
+{
+    let [value] = get_native_strings(initial_length, |[buffer], length| getter(buffer, length))?;
+    Ok(value)
+}
+
+/// Same as [`get_native_string`] for getters that write into several buffers of the same length.
+pub fn get_native_strings<const N: usize, F>(initial_length: usize, mut getter: F) -> Result<[String; N], LexActivatorError>
+where
+    F: FnMut([*mut NativeChar; N], c_uint) -> c_int,
+{
+    let buffer_size_error = LexActivatorError::LA_E_BUFFER_SIZE.code();
+    let mut length = initial_length.clamp(1, MAX_BUFFER_LENGTH);
+    loop {
+        let mut buffers: [Vec<NativeChar>; N] = std::array::from_fn(|_| vec![0; length]);
+        let status = getter(buffers.each_mut().map(|buffer| buffer.as_mut_ptr()), length as c_uint);
+        if status == buffer_size_error && length < MAX_BUFFER_LENGTH {
+            length = (length * 4).min(MAX_BUFFER_LENGTH);
+            continue;
+        }
+        if status != 0 {
+            return Err(LexActivatorError::from(status));
+        }
+        return Ok(buffers.map(|buffer| native_to_string(&buffer)));
+    }
+}
+
+#[cfg(windows)]
+fn native_to_string(buffer: &[u16]) -> String {
+    utf16_to_string(buffer)
+}
+
+#[cfg(not(windows))]
+fn native_to_string(buffer: &[c_char]) -> String {
+    c_char_to_string(buffer)
+}
+
+#[cfg(windows)]
 pub fn utf16_to_string(buffer: &[u16]) -> String {
-    let string = String::from_utf16_lossy(buffer);
-    string.trim_end_matches('\0').to_owned()
+    let end = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..end])
 }
 pub fn c_char_to_string(buffer: &[c_char]) -> String {
     let c_str = unsafe { CStr::from_ptr(buffer.as_ptr()) };
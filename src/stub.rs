@@ -0,0 +1,1158 @@
+//! In-memory implementation of the LexActivator library, enabled by the `stub` feature.
+//!
+//! With the `stub` feature the crate no longer downloads or links the native LexActivator library.
+//! Every function of the crate behaves as if it was talking to the native library and the Cryptlex servers,
+//! but the license state is kept in memory and the "server" side is configured by the functions of this module.
+//!
+//! The state is global to the process, so tests that use the stub should call [`reset_state`] first and
+//! should not run concurrently with each other.
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! stub::reset_state();
+//! stub::set_license(stub::StubLicense::new("LICENSE_KEY"));
+//!
+//! set_product_data("Product.dat_content".to_string())?;
+//! set_product_id("Product_id".to_string(), PermissionFlags::LA_USER)?;
+//! set_license_key("LICENSE_KEY".to_string())?;
+//! assert_eq!(activate_license()?, LexActivatorStatus::LA_OK);
+//! ```
+
+#![allow(non_snake_case)]
+
+use std::collections::HashMap;
+use std::ffi::{c_int, c_longlong, c_uint, c_ulonglong};
+use std::fs;
+use std::sync::{LazyLock, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::extern_functions::CallbackType;
+use crate::string_utils::{is_valid_release_version, NativeChar};
+use crate::LicenseMeterAttribute;
+
+// Status codes returned by the native library.
+const LA_OK: c_int = 0;
+const LA_FAIL: c_int = 1;
+const LA_EXPIRED: c_int = 20;
+const LA_SUSPENDED: c_int = 21;
+const LA_GRACE_PERIOD_OVER: c_int = 22;
+const LA_TRIAL_EXPIRED: c_int = 25;
+const LA_LOCAL_TRIAL_EXPIRED: c_int = 26;
+const LA_E_FILE_PATH: c_int = 40;
+const LA_E_PRODUCT_DATA: c_int = 42;
+const LA_E_PRODUCT_ID: c_int = 43;
+const LA_E_FILE_PERMISSION: c_int = 45;
+const LA_E_INET: c_int = 48;
+const LA_E_NET_PROXY: c_int = 49;
+const LA_E_HOST_URL: c_int = 50;
+const LA_E_BUFFER_SIZE: c_int = 51;
+const LA_E_LICENSE_KEY: c_int = 54;
+const LA_E_OFFLINE_RESPONSE_FILE: c_int = 56;
+const LA_E_ACTIVATION_LIMIT: c_int = 58;
+const LA_E_DEACTIVATION_LIMIT: c_int = 60;
+const LA_E_METADATA_KEY_LENGTH: c_int = 64;
+const LA_E_METADATA_VALUE_LENGTH: c_int = 65;
+const LA_E_ACTIVATION_METADATA_LIMIT: c_int = 66;
+const LA_E_TRIAL_ACTIVATION_METADATA_LIMIT: c_int = 67;
+const LA_E_METADATA_KEY_NOT_FOUND: c_int = 68;
+const LA_E_AUTHENTICATION_FAILED: c_int = 71;
+const LA_E_METER_ATTRIBUTE_NOT_FOUND: c_int = 72;
+const LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED: c_int = 73;
+const LA_E_CUSTOM_FINGERPRINT_LENGTH: c_int = 74;
+const LA_E_PRODUCT_VERSION_NOT_LINKED: c_int = 75;
+const LA_E_RELEASE_PLATFORM_LENGTH: c_int = 78;
+const LA_E_RELEASE_CHANNEL_LENGTH: c_int = 79;
+const LA_E_RELEASE_VERSION: c_int = 84;
+const LA_E_USER_NOT_AUTHENTICATED: c_int = 87;
+const LA_E_AUTHENTICATION_ID_TOKEN_INVALID: c_int = 101;
+const LA_E_INVALID_PERMISSION_FLAG: c_int = 105;
+const LA_E_FEATURE_ENTITLEMENT_NOT_FOUND: c_int = 108;
+const LA_E_ENTITLEMENT_SET_NOT_LINKED: c_int = 109;
+
+const METADATA_LIMIT: usize = 21;
+const TRIAL_LENGTH: u32 = 14 * 24 * 60 * 60;
+
+/// A license as it is known to the stubbed Cryptlex servers.
+///
+/// Dates are in seconds since Unix epoch, where 0 means the date is not set. JSON fields use the same format
+/// as the native library.
+#[derive(Debug, Clone)]
+pub struct StubLicense {
+    /// The license key.
+    pub key: String,
+    /// The license type e.g. node-locked, hosted-floating.
+    pub license_type: String,
+    /// Whether the license has been suspended.
+    pub suspended: bool,
+    /// The creation date of the license.
+    pub creation_date: u32,
+    /// The expiry date of the license, 0 if the license never expires.
+    pub expiry_date: u32,
+    /// The maintenance expiry date of the license.
+    pub maintenance_expiry_date: u32,
+    /// The server sync grace period in seconds, 0 if server sync is not required.
+    pub server_sync_grace_period: u32,
+    /// The allowed activations of the license, -1 for unlimited activations.
+    pub allowed_activations: i64,
+    /// The total activations of the license.
+    pub total_activations: u32,
+    /// The allowed deactivations of the license, -1 for unlimited deactivations.
+    pub allowed_deactivations: i64,
+    /// The total deactivations of the license.
+    pub total_deactivations: u32,
+    /// The maximum allowed release version.
+    pub max_allowed_release_version: String,
+    /// The license metadata.
+    pub metadata: HashMap<String, String>,
+    /// The email of the license user. [`crate::authenticate_user`] succeeds for this email with any non-empty password.
+    pub user_email: String,
+    /// The name of the license user.
+    pub user_name: String,
+    /// The company of the license user.
+    pub user_company: String,
+    /// The license user metadata.
+    pub user_metadata: HashMap<String, String>,
+    /// The organization name.
+    pub organization_name: String,
+    /// The organization address JSON.
+    pub organization_address_json: String,
+    /// The entitlement set name, empty if no entitlement set is linked.
+    pub entitlement_set_name: String,
+    /// The entitlement set display name.
+    pub entitlement_set_display_name: String,
+    /// The entitlement set tier.
+    pub entitlement_set_tier: i64,
+    /// The feature entitlements JSON array.
+    pub feature_entitlements_json: String,
+    /// The user licenses JSON array returned after user authentication.
+    pub user_licenses_json: String,
+    /// The meter attributes of the license, -1 allowed uses for unlimited uses.
+    pub meter_attributes: Vec<LicenseMeterAttribute>,
+}
+
+impl StubLicense {
+    /// Creates a node-locked license with one allowed activation that never expires.
+    pub fn new(key: impl Into<String>) -> Self {
+        StubLicense {
+            key: key.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for StubLicense {
+    fn default() -> Self {
+        StubLicense {
+            key: String::new(),
+            license_type: "node-locked".to_string(),
+            suspended: false,
+            creation_date: now(),
+            expiry_date: 0,
+            maintenance_expiry_date: 0,
+            server_sync_grace_period: 0,
+            allowed_activations: 1,
+            total_activations: 0,
+            allowed_deactivations: -1,
+            total_deactivations: 0,
+            max_allowed_release_version: String::new(),
+            metadata: HashMap::new(),
+            user_email: String::new(),
+            user_name: String::new(),
+            user_company: String::new(),
+            user_metadata: HashMap::new(),
+            organization_name: String::new(),
+            organization_address_json: String::new(),
+            entitlement_set_name: String::new(),
+            entitlement_set_display_name: String::new(),
+            entitlement_set_tier: 0,
+            feature_entitlements_json: String::new(),
+            user_licenses_json: String::new(),
+            meter_attributes: Vec::new(),
+        }
+    }
+}
+
+struct Activation {
+    id: String,
+    activation_date: u32,
+    last_synced_date: u32,
+    metadata: HashMap<String, String>,
+    meter_attribute_uses: HashMap<String, u32>,
+}
+
+struct Trial {
+    id: String,
+    expiry_date: u32,
+}
+
+#[derive(Default)]
+struct StubState {
+    network_available: bool,
+    server_license: Option<StubLicense>,
+    product_metadata: HashMap<String, String>,
+    product_data: Option<String>,
+    product_id: Option<String>,
+    license_key: Option<String>,
+    callback: Option<CallbackType>,
+    activation_metadata: HashMap<String, String>,
+    trial_activation_metadata: HashMap<String, String>,
+    authenticated_user: Option<String>,
+    activation: Option<Activation>,
+    trial: Option<Trial>,
+    local_trial_expiry_date: Option<u32>,
+    next_id: u64,
+}
+
+impl StubState {
+    fn new() -> Self {
+        StubState {
+            network_available: true,
+            ..Default::default()
+        }
+    }
+
+    fn generate_id(&mut self) -> String {
+        self.next_id += 1;
+        format!("stub-{:08}", self.next_id)
+    }
+
+    fn ensure_product(&self) -> Result<(), c_int> {
+        if self.product_data.is_none() {
+            return Err(LA_E_PRODUCT_DATA);
+        }
+        if self.product_id.is_none() {
+            return Err(LA_E_PRODUCT_ID);
+        }
+        Ok(())
+    }
+
+    fn ensure_network(&self) -> Result<(), c_int> {
+        if self.network_available { Ok(()) } else { Err(LA_E_INET) }
+    }
+
+    fn activated_license(&self) -> Result<(&Activation, &StubLicense), c_int> {
+        self.ensure_product()?;
+        match (&self.activation, &self.server_license) {
+            (Some(activation), Some(license)) => Ok((activation, license)),
+            _ => Err(LA_FAIL),
+        }
+    }
+
+    fn license_status(&self) -> Result<c_int, c_int> {
+        let (activation, license) = self.activated_license()?;
+        let now = now();
+        if license.suspended {
+            Ok(LA_SUSPENDED)
+        } else if license.expiry_date != 0 && license.expiry_date <= now {
+            Ok(LA_EXPIRED)
+        } else if license.server_sync_grace_period != 0
+            && activation.last_synced_date.saturating_add(license.server_sync_grace_period) <= now
+        {
+            Ok(LA_GRACE_PERIOD_OVER)
+        } else {
+            Ok(LA_OK)
+        }
+    }
+
+    fn trial_status(&self) -> Result<c_int, c_int> {
+        self.ensure_product()?;
+        match &self.trial {
+            Some(trial) if trial.expiry_date <= now() => Ok(LA_TRIAL_EXPIRED),
+            Some(_) => Ok(LA_OK),
+            None => Err(LA_FAIL),
+        }
+    }
+
+    fn local_trial_status(&self) -> Result<c_int, c_int> {
+        self.ensure_product()?;
+        match self.local_trial_expiry_date {
+            Some(expiry_date) if expiry_date <= now() => Ok(LA_LOCAL_TRIAL_EXPIRED),
+            Some(_) => Ok(LA_OK),
+            None => Err(LA_FAIL),
+        }
+    }
+
+    fn activate(&mut self) -> Result<c_int, c_int> {
+        self.ensure_product()?;
+        let key = self.license_key.clone().ok_or(LA_E_LICENSE_KEY)?;
+        let license = match self.server_license.as_mut() {
+            Some(license) if license.key == key => license,
+            _ => return Err(LA_E_LICENSE_KEY),
+        };
+        if self.activation.is_none() {
+            if license.allowed_activations >= 0 && i64::from(license.total_activations) >= license.allowed_activations {
+                return Err(LA_E_ACTIVATION_LIMIT);
+            }
+            license.total_activations += 1;
+            let id = self.generate_id();
+            self.activation = Some(Activation {
+                id,
+                activation_date: now(),
+                last_synced_date: now(),
+                metadata: self.activation_metadata.clone(),
+                meter_attribute_uses: HashMap::new(),
+            });
+        }
+        self.license_status()
+    }
+
+    fn deactivate(&mut self) -> Result<c_int, c_int> {
+        self.activated_license()?;
+        let license = self.server_license.as_mut().ok_or(LA_FAIL)?;
+        if license.allowed_deactivations >= 0 && i64::from(license.total_deactivations) >= license.allowed_deactivations {
+            return Err(LA_E_DEACTIVATION_LIMIT);
+        }
+        license.total_deactivations += 1;
+        license.total_activations = license.total_activations.saturating_sub(1);
+        self.activation = None;
+        Ok(LA_OK)
+    }
+
+    fn activate_trial(&mut self) -> Result<c_int, c_int> {
+        self.ensure_product()?;
+        if self.trial.is_none() {
+            let id = self.generate_id();
+            self.trial = Some(Trial { id, expiry_date: now().saturating_add(TRIAL_LENGTH) });
+        }
+        self.trial_status()
+    }
+
+    fn meter_attribute(&mut self, name: &str) -> Result<(&mut Activation, &mut LicenseMeterAttribute), c_int> {
+        self.activated_license()?;
+        let activation = self.activation.as_mut().ok_or(LA_FAIL)?;
+        let license = self.server_license.as_mut().ok_or(LA_FAIL)?;
+        let attribute = license
+            .meter_attributes
+            .iter_mut()
+            .find(|attribute| attribute.name == name)
+            .ok_or(LA_E_METER_ATTRIBUTE_NOT_FOUND)?;
+        Ok((activation, attribute))
+    }
+}
+
+static STATE: LazyLock<Mutex<StubState>> = LazyLock::new(|| Mutex::new(StubState::new()));
+
+fn with_state<T>(f: impl FnOnce(&mut StubState) -> T) -> T {
+    let mut state = STATE.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&mut state)
+}
+
+fn now() -> u32 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs() as u32).unwrap_or(0)
+}
+
+fn status(result: Result<c_int, c_int>) -> c_int {
+    result.unwrap_or_else(|code| code)
+}
+
+fn ok(result: Result<(), c_int>) -> c_int {
+    status(result.map(|_| LA_OK))
+}
+
+// --------------- Control functions ---------------
+
+/// Restores the initial state: no product data, no license on the server, network available.
+pub fn reset_state() {
+    with_state(|state| *state = StubState::new());
+}
+
+/// Sets the license known to the stubbed Cryptlex servers. Only this license key can be activated.
+pub fn set_license(license: StubLicense) {
+    with_state(|state| state.server_license = Some(license));
+}
+
+/// Updates the license known to the stubbed Cryptlex servers, e.g. to suspend it or change its expiry date.
+pub fn update_license(f: impl FnOnce(&mut StubLicense)) {
+    with_state(|state| {
+        if let Some(license) = state.server_license.as_mut() {
+            f(license);
+        }
+    });
+}
+
+/// Sets a product metadata value.
+pub fn set_product_metadata(key: impl Into<String>, value: impl Into<String>) {
+    with_state(|state| {
+        state.product_metadata.insert(key.into(), value.into());
+    });
+}
+
+/// Simulates network connectivity. Functions that contact the Cryptlex servers return `LA_E_INET` while the network is unavailable.
+pub fn set_network_available(available: bool) {
+    with_state(|state| state.network_available = available);
+}
+
+/// Invokes the registered license callback with the given status code, as the native library does after a server sync.
+pub fn invoke_license_callback(code: i32) {
+    let callback = with_state(|state| state.callback);
+    if let Some(callback) = callback {
+        callback(code);
+    }
+}
+
+/// Simulates a background server sync of the activation and invokes the license callback with its result.
+pub fn simulate_server_sync() {
+    let code = with_state(|state| {
+        state.ensure_network()?;
+        state.activated_license()?;
+        if let Some(activation) = state.activation.as_mut() {
+            activation.last_synced_date = now();
+        }
+        state.license_status()
+    });
+    invoke_license_callback(status(code));
+}
+
+// --------------- String helpers ---------------
+
+#[cfg(windows)]
+unsafe fn read_str(ptr: *const NativeChar) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    let mut length = 0;
+    while *ptr.add(length) != 0 {
+        length += 1;
+    }
+    String::from_utf16_lossy(std::slice::from_raw_parts(ptr, length))
+}
+
+#[cfg(not(windows))]
+unsafe fn read_str(ptr: *const NativeChar) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    std::ffi::CStr::from_ptr(ptr).to_string_lossy().into_owned()
+}
+
+#[cfg(windows)]
+unsafe fn write_str(value: &str, buffer: *mut NativeChar, length: c_uint) -> Result<(), c_int> {
+    let encoded: Vec<u16> = value.encode_utf16().chain(std::iter::once(0)).collect();
+    copy_to_buffer(&encoded, buffer, length)
+}
+
+#[cfg(not(windows))]
+unsafe fn write_str(value: &str, buffer: *mut NativeChar, length: c_uint) -> Result<(), c_int> {
+    let encoded: Vec<NativeChar> = value.bytes().chain(std::iter::once(0)).map(|byte| byte as NativeChar).collect();
+    copy_to_buffer(&encoded, buffer, length)
+}
+
+unsafe fn copy_to_buffer(encoded: &[NativeChar], buffer: *mut NativeChar, length: c_uint) -> Result<(), c_int> {
+    if encoded.len() > length as usize {
+        return Err(LA_E_BUFFER_SIZE);
+    }
+    std::ptr::copy_nonoverlapping(encoded.as_ptr(), buffer, encoded.len());
+    Ok(())
+}
+
+unsafe fn write_value<T>(value: T, out: *mut T) -> Result<(), c_int> {
+    *out = value;
+    Ok(())
+}
+
+fn set_metadata(metadata: &mut HashMap<String, String>, key: String, value: String, limit_error: c_int) -> Result<(), c_int> {
+    if key.is_empty() || key.chars().count() > 256 {
+        return Err(LA_E_METADATA_KEY_LENGTH);
+    }
+    if value.chars().count() > 4096 {
+        return Err(LA_E_METADATA_VALUE_LENGTH);
+    }
+    if !metadata.contains_key(&key) && metadata.len() >= METADATA_LIMIT {
+        return Err(limit_error);
+    }
+    metadata.insert(key, value);
+    Ok(())
+}
+
+fn write_request_file(path: &str, kind: &str, id: &str) -> Result<(), c_int> {
+    if path.is_empty() {
+        return Err(LA_E_FILE_PATH);
+    }
+    let request = serde_json::json!({ "type": kind, "id": id, "createdAt": now() });
+    fs::write(path, request.to_string()).map_err(|_| LA_E_FILE_PERMISSION)
+}
+
+fn read_response_file(path: &str) -> Result<(), c_int> {
+    match fs::read_to_string(path) {
+        Ok(content) if !content.trim().is_empty() => Ok(()),
+        Ok(_) => Err(LA_E_OFFLINE_RESPONSE_FILE),
+        Err(_) => Err(LA_E_FILE_PATH),
+    }
+}
+
+// --------------- Setter Functions ---------------
+
+pub(crate) unsafe fn SetProductData(productData: *const NativeChar) -> c_int {
+    let product_data = read_str(productData);
+    ok(with_state(|state| {
+        if product_data.trim().is_empty() {
+            return Err(LA_E_PRODUCT_DATA);
+        }
+        state.product_data = Some(product_data);
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetProductId(productId: *const NativeChar, flags: c_uint) -> c_int {
+    let product_id = read_str(productId);
+    ok(with_state(|state| {
+        if state.product_data.is_none() {
+            return Err(LA_E_PRODUCT_DATA);
+        }
+        if product_id.trim().is_empty() {
+            return Err(LA_E_PRODUCT_ID);
+        }
+        if !(1..=4).contains(&flags) {
+            return Err(LA_E_INVALID_PERMISSION_FLAG);
+        }
+        state.product_id = Some(product_id);
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetDataDirectory(dataDir: *const NativeChar) -> c_int {
+    let data_dir = read_str(dataDir);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if data_dir.is_empty() {
+            return Err(LA_E_FILE_PATH);
+        }
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetDebugMode(_enable: c_uint) -> c_int {
+    LA_OK
+}
+
+pub(crate) unsafe fn SetCacheMode(_mode: c_uint) -> c_int {
+    ok(with_state(|state| state.ensure_product()))
+}
+
+pub(crate) unsafe fn SetCustomDeviceFingerprint(deviceFingerprint: *const NativeChar) -> c_int {
+    let length = read_str(deviceFingerprint).chars().count();
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if !(64..=256).contains(&length) {
+            return Err(LA_E_CUSTOM_FINGERPRINT_LENGTH);
+        }
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetLicenseKey(licenseKey: *const NativeChar) -> c_int {
+    let license_key = read_str(licenseKey);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if license_key.trim().is_empty() {
+            return Err(LA_E_LICENSE_KEY);
+        }
+        state.license_key = Some(license_key);
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetLicenseUserCredential(email: *const NativeChar, password: *const NativeChar) -> c_int {
+    let (email, password) = (read_str(email), read_str(password));
+    status(with_state(|state| {
+        state.ensure_product()?;
+        if email.is_empty() || password.is_empty() {
+            return Err(LA_E_AUTHENTICATION_FAILED);
+        }
+        Ok(LA_OK)
+    }))
+}
+
+pub(crate) unsafe fn SetLicenseCallback(callback: CallbackType) -> c_int {
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        state.callback = Some(callback);
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetActivationLeaseDuration(_leaseDuration: c_longlong) -> c_int {
+    ok(with_state(|state| state.ensure_product()))
+}
+
+pub(crate) unsafe fn SetActivationMetadata(key: *const NativeChar, value: *const NativeChar) -> c_int {
+    let (key, value) = (read_str(key), read_str(value));
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        set_metadata(&mut state.activation_metadata, key, value, LA_E_ACTIVATION_METADATA_LIMIT)
+    }))
+}
+
+pub(crate) unsafe fn SetTrialActivationMetadata(key: *const NativeChar, value: *const NativeChar) -> c_int {
+    let (key, value) = (read_str(key), read_str(value));
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        set_metadata(&mut state.trial_activation_metadata, key, value, LA_E_TRIAL_ACTIVATION_METADATA_LIMIT)
+    }))
+}
+
+pub(crate) unsafe fn SetReleaseVersion(releaseVersion: *const NativeChar) -> c_int {
+    let release_version = read_str(releaseVersion);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if !is_valid_release_version(&release_version) {
+            return Err(LA_E_RELEASE_VERSION);
+        }
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetReleasePublishedDate(_releasePublishedDate: c_uint) -> c_int {
+    ok(with_state(|state| state.ensure_product()))
+}
+
+pub(crate) unsafe fn SetReleasePlatform(platform: *const NativeChar) -> c_int {
+    let platform = read_str(platform);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if platform.chars().count() > 256 {
+            return Err(LA_E_RELEASE_PLATFORM_LENGTH);
+        }
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetReleaseChannel(channel: *const NativeChar) -> c_int {
+    let channel = read_str(channel);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if channel.chars().count() > 256 {
+            return Err(LA_E_RELEASE_CHANNEL_LENGTH);
+        }
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetOfflineActivationRequestMeterAttributeUses(_name: *const NativeChar, _uses: c_uint) -> c_int {
+    ok(with_state(|state| state.ensure_product()))
+}
+
+pub(crate) unsafe fn SetNetworkProxy(proxy: *const NativeChar) -> c_int {
+    let proxy = read_str(proxy);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if !proxy.is_empty() && !proxy.contains("://") {
+            return Err(LA_E_NET_PROXY);
+        }
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetCryptlexHost(host: *const NativeChar) -> c_int {
+    let host = read_str(host);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if !host.starts_with("https://") && !host.starts_with("http://") {
+            return Err(LA_E_HOST_URL);
+        }
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn SetTwoFactorAuthenticationCode(_twoFactorAuthenticationCode: *const NativeChar) -> c_int {
+    ok(with_state(|state| state.ensure_product()))
+}
+
+// --------------- Getter Functions ---------------
+
+pub(crate) unsafe fn GetProductMetadata(key: *const NativeChar, value: *mut NativeChar, length: c_uint) -> c_int {
+    let key = read_str(key);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        let metadata_value = state.product_metadata.get(&key).ok_or(LA_E_METADATA_KEY_NOT_FOUND)?;
+        write_str(metadata_value, value, length)
+    }))
+}
+
+pub(crate) unsafe fn GetProductVersionName(_name: *mut NativeChar, _length: c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.activated_license()?;
+        Err(LA_E_PRODUCT_VERSION_NOT_LINKED)
+    }))
+}
+
+pub(crate) unsafe fn GetProductVersionDisplayName(_name: *mut NativeChar, _length: c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.activated_license()?;
+        Err(LA_E_PRODUCT_VERSION_NOT_LINKED)
+    }))
+}
+
+pub(crate) unsafe fn GetProductVersionFeatureFlag(_name: *const NativeChar, _enabled: *mut c_uint, _data: *mut NativeChar, _length: c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.activated_license()?;
+        Err(LA_E_PRODUCT_VERSION_NOT_LINKED)
+    }))
+}
+
+pub(crate) unsafe fn GetLicenseMetadata(key: *const NativeChar, value: *mut NativeChar, length: c_uint) -> c_int {
+    let key = read_str(key);
+    ok(with_state(|state| {
+        let (_, license) = state.activated_license()?;
+        let metadata_value = license.metadata.get(&key).ok_or(LA_E_METADATA_KEY_NOT_FOUND)?;
+        write_str(metadata_value, value, length)
+    }))
+}
+
+pub(crate) unsafe fn GetLicenseKey(licenseKey: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| write_str(&state.activated_license()?.1.key, licenseKey, length)))
+}
+
+pub(crate) unsafe fn GetLicenseAllowedActivations(allowedActivations: *mut c_longlong) -> c_int {
+    ok(with_state(|state| write_value(state.activated_license()?.1.allowed_activations, allowedActivations)))
+}
+
+pub(crate) unsafe fn GetLicenseAllowedDeactivations(allowedDeactivations: *mut c_longlong) -> c_int {
+    ok(with_state(|state| write_value(state.activated_license()?.1.allowed_deactivations, allowedDeactivations)))
+}
+
+pub(crate) unsafe fn GetLicenseTotalActivations(totalActivations: *mut c_uint) -> c_int {
+    ok(with_state(|state| write_value(state.activated_license()?.1.total_activations, totalActivations)))
+}
+
+pub(crate) unsafe fn GetLicenseTotalDeactivations(totalDeactivations: *mut c_uint) -> c_int {
+    ok(with_state(|state| write_value(state.activated_license()?.1.total_deactivations, totalDeactivations)))
+}
+
+pub(crate) unsafe fn GetLicenseCreationDate(creationDate: *mut c_uint) -> c_int {
+    ok(with_state(|state| write_value(state.activated_license()?.1.creation_date, creationDate)))
+}
+
+pub(crate) unsafe fn GetLicenseActivationDate(activationDate: *mut c_uint) -> c_int {
+    ok(with_state(|state| write_value(state.activated_license()?.0.activation_date, activationDate)))
+}
+
+pub(crate) unsafe fn GetActivationLastSyncedDate(lastSyncedDate: *mut c_uint) -> c_int {
+    ok(with_state(|state| write_value(state.activated_license()?.0.last_synced_date, lastSyncedDate)))
+}
+
+pub(crate) unsafe fn GetLicenseExpiryDate(expiryDate: *mut c_uint) -> c_int {
+    ok(with_state(|state| write_value(state.activated_license()?.1.expiry_date, expiryDate)))
+}
+
+pub(crate) unsafe fn GetLicenseMaintenanceExpiryDate(maintenanceExpiryDate: *mut c_uint) -> c_int {
+    ok(with_state(|state| write_value(state.activated_license()?.1.maintenance_expiry_date, maintenanceExpiryDate)))
+}
+
+pub(crate) unsafe fn GetLicenseMaxAllowedReleaseVersion(maxAllowedReleaseVersion: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| write_str(&state.activated_license()?.1.max_allowed_release_version, maxAllowedReleaseVersion, length)))
+}
+
+pub(crate) unsafe fn GetLicenseUserEmail(email: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| write_str(&state.activated_license()?.1.user_email, email, length)))
+}
+
+pub(crate) unsafe fn GetLicenseUserName(name: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| write_str(&state.activated_license()?.1.user_name, name, length)))
+}
+
+pub(crate) unsafe fn GetLicenseUserCompany(company: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| write_str(&state.activated_license()?.1.user_company, company, length)))
+}
+
+pub(crate) unsafe fn GetLicenseUserMetadata(key: *const NativeChar, value: *mut NativeChar, length: c_uint) -> c_int {
+    let key = read_str(key);
+    ok(with_state(|state| {
+        let (_, license) = state.activated_license()?;
+        let metadata_value = license.user_metadata.get(&key).ok_or(LA_E_METADATA_KEY_NOT_FOUND)?;
+        write_str(metadata_value, value, length)
+    }))
+}
+
+pub(crate) unsafe fn GetLicenseOrganizationName(organizationName: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| write_str(&state.activated_license()?.1.organization_name, organizationName, length)))
+}
+
+pub(crate) unsafe fn GetLicenseOrganizationAddressInternal(organizationAddressJson: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| write_str(&state.activated_license()?.1.organization_address_json, organizationAddressJson, length)))
+}
+
+pub(crate) unsafe fn GetLicenseEntitlementSetName(name: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| {
+        let (_, license) = state.activated_license()?;
+        if license.entitlement_set_name.is_empty() {
+            return Err(LA_E_ENTITLEMENT_SET_NOT_LINKED);
+        }
+        write_str(&license.entitlement_set_name, name, length)
+    }))
+}
+
+pub(crate) unsafe fn GetLicenseEntitlementSetDisplayName(displayName: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| {
+        let (_, license) = state.activated_license()?;
+        if license.entitlement_set_name.is_empty() {
+            return Err(LA_E_ENTITLEMENT_SET_NOT_LINKED);
+        }
+        write_str(&license.entitlement_set_display_name, displayName, length)
+    }))
+}
+
+pub(crate) unsafe fn GetLicenseEntitlementSetTier(tier: *mut c_longlong) -> c_int {
+    ok(with_state(|state| {
+        let (_, license) = state.activated_license()?;
+        if license.entitlement_set_name.is_empty() {
+            return Err(LA_E_ENTITLEMENT_SET_NOT_LINKED);
+        }
+        write_value(license.entitlement_set_tier, tier)
+    }))
+}
+
+pub(crate) unsafe fn GetFeatureEntitlementsInternal(featureEntitlementsJson: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| {
+        let (_, license) = state.activated_license()?;
+        if license.entitlement_set_name.is_empty() && license.feature_entitlements_json.is_empty() {
+            return Err(LA_E_ENTITLEMENT_SET_NOT_LINKED);
+        }
+        write_str(&license.feature_entitlements_json, featureEntitlementsJson, length)
+    }))
+}
+
+pub(crate) unsafe fn GetFeatureEntitlementInternal(featureName: *const NativeChar, featureEntitlementJson: *mut NativeChar, length: c_uint) -> c_int {
+    let feature_name = read_str(featureName);
+    ok(with_state(|state| {
+        let (_, license) = state.activated_license()?;
+        if license.entitlement_set_name.is_empty() && license.feature_entitlements_json.is_empty() {
+            return Err(LA_E_ENTITLEMENT_SET_NOT_LINKED);
+        }
+        let entitlements: Vec<serde_json::Value> = serde_json::from_str(&license.feature_entitlements_json).unwrap_or_default();
+        let entitlement = entitlements
+            .iter()
+            .find(|entitlement| entitlement["featureName"] == feature_name.as_str())
+            .ok_or(LA_E_FEATURE_ENTITLEMENT_NOT_FOUND)?;
+        write_str(&entitlement.to_string(), featureEntitlementJson, length)
+    }))
+}
+
+pub(crate) unsafe fn GetUserLicensesInternal(userLicenses: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if state.authenticated_user.is_none() {
+            return Err(LA_E_USER_NOT_AUTHENTICATED);
+        }
+        state.ensure_network()?;
+        let user_licenses_json = state.server_license.as_ref().map(|license| license.user_licenses_json.as_str()).unwrap_or("");
+        write_str(user_licenses_json, userLicenses, length)
+    }))
+}
+
+pub(crate) unsafe fn GetLicenseType(licenseType: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| write_str(&state.activated_license()?.1.license_type, licenseType, length)))
+}
+
+pub(crate) unsafe fn GetActivationId(id: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| write_str(&state.activated_license()?.0.id, id, length)))
+}
+
+pub(crate) unsafe fn GetActivationMetadata(key: *const NativeChar, value: *mut NativeChar, length: c_uint) -> c_int {
+    let key = read_str(key);
+    ok(with_state(|state| {
+        let (activation, _) = state.activated_license()?;
+        let metadata_value = activation.metadata.get(&key).ok_or(LA_E_METADATA_KEY_NOT_FOUND)?;
+        write_str(metadata_value, value, length)
+    }))
+}
+
+pub(crate) unsafe fn GetActivationMode(initialMode: *mut NativeChar, initialModeLength: c_uint, currentMode: *mut NativeChar, currentModeLength: c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.activated_license()?;
+        write_str("online", initialMode, initialModeLength)?;
+        write_str("online", currentMode, currentModeLength)
+    }))
+}
+
+pub(crate) unsafe fn GetActivationMeterAttributeUses(name: *const NativeChar, uses: *mut c_uint) -> c_int {
+    let name = read_str(name);
+    ok(with_state(|state| {
+        let (activation, _) = state.meter_attribute(&name)?;
+        write_value(activation.meter_attribute_uses.get(&name).copied().unwrap_or(0), uses)
+    }))
+}
+
+pub(crate) unsafe fn GetServerSyncGracePeriodExpiryDate(gracePeriodExpiryDate: *mut c_uint) -> c_int {
+    ok(with_state(|state| {
+        let (activation, license) = state.activated_license()?;
+        let expiry_date = match license.server_sync_grace_period {
+            0 => 0,
+            grace_period => activation.last_synced_date.saturating_add(grace_period),
+        };
+        write_value(expiry_date, gracePeriodExpiryDate)
+    }))
+}
+
+pub(crate) unsafe fn GetLastActivationError(errorCode: *mut c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        write_value(0, errorCode)
+    }))
+}
+
+pub(crate) unsafe fn GetTrialActivationMetadata(key: *const NativeChar, value: *mut NativeChar, length: c_uint) -> c_int {
+    let key = read_str(key);
+    ok(with_state(|state| {
+        state.trial_status()?;
+        let metadata_value = state.trial_activation_metadata.get(&key).ok_or(LA_E_METADATA_KEY_NOT_FOUND)?;
+        write_str(metadata_value, value, length)
+    }))
+}
+
+pub(crate) unsafe fn GetTrialExpiryDate(trialExpiryDate: *mut c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.trial_status()?;
+        write_value(state.trial.as_ref().map_or(0, |trial| trial.expiry_date), trialExpiryDate)
+    }))
+}
+
+pub(crate) unsafe fn GetTrialId(trialId: *mut NativeChar, length: c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.trial_status()?;
+        write_str(state.trial.as_ref().map_or("", |trial| trial.id.as_str()), trialId, length)
+    }))
+}
+
+pub(crate) unsafe fn GetLocalTrialExpiryDate(localTrialExpiryDate: *mut c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.local_trial_status()?;
+        write_value(state.local_trial_expiry_date.unwrap_or(0), localTrialExpiryDate)
+    }))
+}
+
+pub(crate) unsafe fn GetLibraryVersion(libraryVersion: *mut NativeChar, length: c_uint) -> c_int {
+    ok(write_str(concat!(env!("CARGO_PKG_VERSION"), "-stub"), libraryVersion, length))
+}
+
+pub(crate) unsafe fn GetLicenseMeterAttribute(name: *const NativeChar, allowedUses: *mut c_longlong, totalUses: *mut c_ulonglong, grossUses: *mut c_ulonglong) -> c_int {
+    let name = read_str(name);
+    ok(with_state(|state| {
+        let (_, attribute) = state.meter_attribute(&name)?;
+        write_value(attribute.allowed_uses, allowedUses)?;
+        write_value(attribute.total_uses, totalUses)?;
+        write_value(attribute.gross_uses, grossUses)
+    }))
+}
+
+// --------------- LexActivator Action Functions ---------------
+
+pub(crate) unsafe fn AuthenticateUser(email: *const NativeChar, password: *const NativeChar) -> c_int {
+    let (email, password) = (read_str(email), read_str(password));
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        state.ensure_network()?;
+        let user_email = state.server_license.as_ref().map(|license| license.user_email.as_str());
+        if password.is_empty() || email.is_empty() || user_email != Some(email.as_str()) {
+            return Err(LA_E_AUTHENTICATION_FAILED);
+        }
+        state.authenticated_user = Some(email);
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn AuthenticateUserWithIdToken(idToken: *const NativeChar) -> c_int {
+    let id_token = read_str(idToken);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        state.ensure_network()?;
+        if id_token.is_empty() {
+            return Err(LA_E_AUTHENTICATION_ID_TOKEN_INVALID);
+        }
+        state.authenticated_user = state.server_license.as_ref().map(|license| license.user_email.clone());
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn ActivateLicense() -> c_int {
+    status(with_state(|state| {
+        state.ensure_product()?;
+        state.ensure_network()?;
+        state.activate()
+    }))
+}
+
+pub(crate) unsafe fn ActivateLicenseOffline(filePath: *const NativeChar) -> c_int {
+    let file_path = read_str(filePath);
+    status(with_state(|state| {
+        state.ensure_product()?;
+        read_response_file(&file_path)?;
+        state.activate()
+    }))
+}
+
+pub(crate) unsafe fn GenerateOfflineActivationRequest(filePath: *const NativeChar) -> c_int {
+    let file_path = read_str(filePath);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        let license_key = state.license_key.clone().ok_or(LA_E_LICENSE_KEY)?;
+        write_request_file(&file_path, "activation", &license_key)
+    }))
+}
+
+pub(crate) unsafe fn DeactivateLicense() -> c_int {
+    status(with_state(|state| {
+        state.ensure_product()?;
+        state.ensure_network()?;
+        state.deactivate()
+    }))
+}
+
+pub(crate) unsafe fn GenerateOfflineDeactivationRequest(filePath: *const NativeChar) -> c_int {
+    let file_path = read_str(filePath);
+    status(with_state(|state| {
+        let activation_id = state.activated_license()?.0.id.clone();
+        write_request_file(&file_path, "deactivation", &activation_id)?;
+        state.deactivate()
+    }))
+}
+
+pub(crate) unsafe fn IsLicenseGenuine() -> c_int {
+    status(with_state(|state| {
+        state.activated_license()?;
+        if state.network_available {
+            if let Some(activation) = state.activation.as_mut() {
+                activation.last_synced_date = now();
+            }
+        }
+        state.license_status()
+    }))
+}
+
+pub(crate) unsafe fn IsLicenseValid() -> c_int {
+    status(with_state(|state| state.license_status()))
+}
+
+pub(crate) unsafe fn SyncLicenseActivation() -> c_int {
+    status(with_state(|state| {
+        state.activated_license()?;
+        state.ensure_network()?;
+        if let Some(activation) = state.activation.as_mut() {
+            activation.last_synced_date = now();
+        }
+        state.license_status()
+    }))
+}
+
+pub(crate) unsafe fn ActivateTrial() -> c_int {
+    status(with_state(|state| {
+        state.ensure_product()?;
+        state.ensure_network()?;
+        state.activate_trial()
+    }))
+}
+
+pub(crate) unsafe fn SyncTrialActivation() -> c_int {
+    status(with_state(|state| {
+        state.trial_status()?;
+        state.ensure_network()?;
+        state.trial_status()
+    }))
+}
+
+pub(crate) unsafe fn ActivateTrialOffline(filePath: *const NativeChar) -> c_int {
+    let file_path = read_str(filePath);
+    status(with_state(|state| {
+        state.ensure_product()?;
+        read_response_file(&file_path)?;
+        state.activate_trial()
+    }))
+}
+
+pub(crate) unsafe fn GenerateOfflineTrialActivationRequest(filePath: *const NativeChar) -> c_int {
+    let file_path = read_str(filePath);
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        let product_id = state.product_id.clone().unwrap_or_default();
+        write_request_file(&file_path, "trial", &product_id)
+    }))
+}
+
+pub(crate) unsafe fn IsTrialGenuine() -> c_int {
+    status(with_state(|state| state.trial_status()))
+}
+
+pub(crate) unsafe fn ActivateLocalTrial(trialLength: c_uint) -> c_int {
+    status(with_state(|state| {
+        state.ensure_product()?;
+        if state.local_trial_expiry_date.is_none() {
+            state.local_trial_expiry_date = Some(now().saturating_add(trialLength.saturating_mul(24 * 60 * 60)));
+        }
+        state.local_trial_status()
+    }))
+}
+
+pub(crate) unsafe fn IsLocalTrialGenuine() -> c_int {
+    status(with_state(|state| state.local_trial_status()))
+}
+
+pub(crate) unsafe fn ExtendLocalTrial(trialExtensionLength: c_uint) -> c_int {
+    status(with_state(|state| {
+        state.local_trial_status()?;
+        let extension = trialExtensionLength.saturating_mul(24 * 60 * 60);
+        state.local_trial_expiry_date = state.local_trial_expiry_date.map(|expiry_date| expiry_date.saturating_add(extension));
+        Ok(LA_OK)
+    }))
+}
+
+pub(crate) unsafe fn IncrementActivationMeterAttributeUses(name: *const NativeChar, increment: c_uint) -> c_int {
+    let name = read_str(name);
+    ok(with_state(|state| {
+        state.meter_attribute(&name)?;
+        state.ensure_network()?;
+        let (activation, attribute) = state.meter_attribute(&name)?;
+        if attribute.allowed_uses >= 0 && attribute.total_uses + u64::from(increment) > attribute.allowed_uses as u64 {
+            return Err(LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED);
+        }
+        attribute.total_uses += u64::from(increment);
+        attribute.gross_uses += u64::from(increment);
+        *activation.meter_attribute_uses.entry(name).or_insert(0) += increment;
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn DecrementActivationMeterAttributeUses(name: *const NativeChar, decrement: c_uint) -> c_int {
+    let name = read_str(name);
+    ok(with_state(|state| {
+        state.meter_attribute(&name)?;
+        state.ensure_network()?;
+        let (activation, attribute) = state.meter_attribute(&name)?;
+        attribute.total_uses = attribute.total_uses.saturating_sub(u64::from(decrement));
+        let uses = activation.meter_attribute_uses.entry(name).or_insert(0);
+        *uses = uses.saturating_sub(decrement);
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn ResetActivationMeterAttributeUses(name: *const NativeChar) -> c_int {
+    let name = read_str(name);
+    ok(with_state(|state| {
+        state.meter_attribute(&name)?;
+        state.ensure_network()?;
+        let (activation, attribute) = state.meter_attribute(&name)?;
+        let uses = activation.meter_attribute_uses.remove(&name).unwrap_or(0);
+        attribute.total_uses = attribute.total_uses.saturating_sub(u64::from(uses));
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn MigrateToSystemWideActivation(oldPermissionFlag: c_uint) -> c_int {
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        if !(1..=4).contains(&oldPermissionFlag) {
+            return Err(LA_E_INVALID_PERMISSION_FLAG);
+        }
+        Ok(())
+    }))
+}
+
+pub(crate) unsafe fn Reset() -> c_int {
+    ok(with_state(|state| {
+        state.ensure_product()?;
+        state.activation = None;
+        state.trial = None;
+        state.authenticated_user = None;
+        Ok(())
+    }))
+}
@@ -0,0 +1,18 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// --------------------------- Time operations ------------------------
+
+/// Converts a timestamp in seconds since the Unix epoch, as returned by the LexActivator library, to a `SystemTime`.
+/// A timestamp of 0 (or less) means that the date is not set, e.g. the license never expires.
+pub fn timestamp_to_system_time(timestamp: impl Into<i64>) -> Option<SystemTime> {
+    let timestamp = timestamp.into();
+    if timestamp <= 0 {
+        return None;
+    }
+    UNIX_EPOCH.checked_add(Duration::from_secs(timestamp as u64))
+}
+
+/// Returns the time left until `time`, or a zero duration if it has already passed. `None` is passed through.
+pub fn time_remaining(time: Option<SystemTime>) -> Option<Duration> {
+    time.map(|time| time.duration_since(SystemTime::now()).unwrap_or(Duration::ZERO))
+}
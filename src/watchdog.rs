@@ -0,0 +1,257 @@
+//! Periodic revalidation of the license on a background thread.
+//!
+//! [`LicenseWatchdog`] calls [`is_license_genuine`] at a fixed interval, or [`is_license_valid`] for offline
+//! activations and when the Cryptlex servers cannot be reached. A [`LicenseAccessPolicy`] maps each result to
+//! the [`LicenseAccess`] the application grants, e.g. read-only access once the server sync grace period is
+//! over. Every change of the result or the access is published as a [`WatchdogTransition`] to the closures
+//! and channels added to the [`LicenseWatchdogBuilder`].
+//!
+//! ```ignore
+//! use lexactivator::*;
+//!
+//! let watchdog = LicenseWatchdog::builder()
+//!     .interval(Duration::from_secs(15 * 60))
+//!     .on_transition(|transition| log::info!("License: {:?} -> {:?}", transition.previous_access, transition.access))
+//!     .start()?;
+//!
+//! if watchdog.access() == LicenseAccess::ReadOnly {
+//!     // ...
+//! }
+//!
+//! watchdog.stop();
+//! ```
+
+use std::fmt;
+use std::sync::{mpsc, Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use crate::periodic::{self, PeriodicThread};
+use crate::*;
+
+/// Default interval between two checks of the watchdog.
+pub const DEFAULT_WATCHDOG_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Shortest interval between two checks of the watchdog. Shorter intervals are raised to it.
+pub const MIN_WATCHDOG_INTERVAL: Duration = periodic::MIN_INTERVAL;
+
+/// The access the application grants, as decided by a [`LicenseAccessPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LicenseAccess {
+    /// All features are available.
+    Full,
+    /// Existing data can be viewed, but not changed.
+    ReadOnly,
+    /// The application must not be used.
+    Denied,
+}
+
+/// Maps the result of a license check to the access the application grants.
+pub type LicenseAccessPolicy = Arc<dyn Fn(&Result<LexActivatorStatus, LexActivatorError>) -> LicenseAccess + Send + Sync + 'static>;
+
+/// The default [`LicenseAccessPolicy`]: full access for a genuine license, read-only access once the server
+/// sync grace period is over, and no access otherwise.
+pub fn default_license_access_policy(result: &Result<LexActivatorStatus, LexActivatorError>) -> LicenseAccess {
+    match result {
+        Ok(LexActivatorStatus::LA_OK) => LicenseAccess::Full,
+        Ok(LexActivatorStatus::LA_GRACE_PERIOD_OVER) => LicenseAccess::ReadOnly,
+        _ => LicenseAccess::Denied,
+    }
+}
+
+/// A change of the license check result or of the access, published by the [`LicenseWatchdog`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchdogTransition {
+    /// The result of the previous check, or `None` for the first check.
+    pub previous: Option<Result<LexActivatorStatus, LexActivatorError>>,
+    /// The result of the current check.
+    pub current: Result<LexActivatorStatus, LexActivatorError>,
+    /// The access after the previous check, or `None` for the first check.
+    pub previous_access: Option<LicenseAccess>,
+    /// The access after the current check.
+    pub access: LicenseAccess,
+}
+
+type TransitionHandler = Arc<dyn Fn(&WatchdogTransition) + Send + Sync + 'static>;
+
+/// Builder for a [`LicenseWatchdog`], created by [`LicenseWatchdog::builder`].
+pub struct LicenseWatchdogBuilder {
+    interval: Duration,
+    policy: LicenseAccessPolicy,
+    handlers: Vec<TransitionHandler>,
+}
+
+impl fmt::Debug for LicenseWatchdogBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LicenseWatchdogBuilder").field("interval", &self.interval).finish_non_exhaustive()
+    }
+}
+
+impl Default for LicenseWatchdogBuilder {
+    fn default() -> Self {
+        LicenseWatchdogBuilder {
+            interval: DEFAULT_WATCHDOG_INTERVAL,
+            policy: Arc::new(default_license_access_policy),
+            handlers: Vec::new(),
+        }
+    }
+}
+
+impl LicenseWatchdogBuilder {
+    /// Creates a builder with the default interval and [`default_license_access_policy`].
+    pub fn new() -> Self {
+        LicenseWatchdogBuilder::default()
+    }
+
+    /// Sets the interval between two checks. Defaults to [`DEFAULT_WATCHDOG_INTERVAL`], and is at least
+    /// [`MIN_WATCHDOG_INTERVAL`].
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval.max(MIN_WATCHDOG_INTERVAL);
+        self
+    }
+
+    /// Sets the policy that maps the result of each check to a [`LicenseAccess`]. If the policy panics, the
+    /// panic is passed to the handler of [`set_license_callback_panic_handler`] and the access is
+    /// [`LicenseAccess::Denied`].
+    pub fn policy<F>(mut self, policy: F) -> Self
+    where
+        F: Fn(&Result<LexActivatorStatus, LexActivatorError>) -> LicenseAccess + Send + Sync + 'static,
+    {
+        self.policy = Arc::new(policy);
+        self
+    }
+
+    /// Adds a closure that is called with every transition, on the watchdog thread.
+    ///
+    /// The closure is also called with the first check, which has no previous result, on the thread that starts the watchdog.
+    pub fn on_transition<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&WatchdogTransition) + Send + Sync + 'static,
+    {
+        self.handlers.push(Arc::new(handler));
+        self
+    }
+
+    /// Adds a channel that receives every transition, like the closures of [`LicenseWatchdogBuilder::on_transition`].
+    /// Transitions are discarded once the receiver is dropped.
+    pub fn transition_channel(self, sender: mpsc::Sender<WatchdogTransition>) -> Self {
+        self.on_transition(move |transition| {
+            let _ = sender.send(transition.clone());
+        })
+    }
+
+    /// Starts the watchdog. The first check runs before this function returns.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(LicenseWatchdog)` if the watchdog thread is started successfully. If the thread cannot be spawned, an `Err` containing the `std::io::Error` is returned.
+    pub fn start(self) -> std::io::Result<LicenseWatchdog> {
+        self.start_with(LexActivator::new())
+    }
+
+    /// Same as [`LicenseWatchdogBuilder::start`], checking the license through the given handle.
+    pub fn start_with<B>(self, lexactivator: LexActivator<B>) -> std::io::Result<LicenseWatchdog>
+    where
+        B: LicensingBackend + Send + Sync + 'static,
+    {
+        let shared = Arc::new(Shared { state: Mutex::new(State { last: None, access: None }) });
+        let worker = Worker { lexactivator, shared: Arc::clone(&shared), policy: self.policy, handlers: self.handlers };
+        worker.check();
+        let thread = PeriodicThread::spawn("lexactivator-watchdog", self.interval, move || worker.check())?;
+        Ok(LicenseWatchdog { shared, thread })
+    }
+}
+
+struct State {
+    last: Option<Result<LexActivatorStatus, LexActivatorError>>,
+    access: Option<LicenseAccess>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for Shared {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("Shared").field("last", &state.last).field("access", &state.access).finish_non_exhaustive()
+    }
+}
+
+struct Worker<B: LicensingBackend> {
+    lexactivator: LexActivator<B>,
+    shared: Arc<Shared>,
+    policy: LicenseAccessPolicy,
+    handlers: Vec<TransitionHandler>,
+}
+
+impl<B: LicensingBackend> Worker<B> {
+    fn validate(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+        let _guard = API_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let offline = self
+            .lexactivator
+            .get_activation_mode()
+            .is_ok_and(|mode| mode.current() == ActivationModeKind::Offline);
+        if offline {
+            return self.lexactivator.is_license_valid();
+        }
+        match self.lexactivator.is_license_genuine() {
+            Err(error) if error.is_network_related() => self.lexactivator.is_license_valid(),
+            result => result,
+        }
+    }
+
+    fn check(&self) {
+        let current = self.validate();
+        // A panicking policy denies access instead of stopping the watchdog.
+        let mut access = LicenseAccess::Denied;
+        events::call_guarded(|| access = (self.policy)(&current));
+        let transition = {
+            let mut state = self.shared.lock();
+            if state.last.as_ref() == Some(&current) && state.access == Some(access) {
+                return;
+            }
+            let previous = state.last.replace(current.clone());
+            let previous_access = state.access.replace(access);
+            WatchdogTransition { previous, current, previous_access, access }
+        };
+        for handler in &self.handlers {
+            events::call_guarded(|| handler(&transition));
+        }
+    }
+}
+
+/// A running license watchdog. Dropping the watchdog stops it and waits for its thread to finish.
+#[derive(Debug)]
+pub struct LicenseWatchdog {
+    shared: Arc<Shared>,
+    thread: PeriodicThread,
+}
+
+impl LicenseWatchdog {
+    /// Returns a builder to configure the watchdog.
+    pub fn builder() -> LicenseWatchdogBuilder {
+        LicenseWatchdogBuilder::new()
+    }
+
+    /// Returns the access decided by the policy for the last check.
+    pub fn access(&self) -> LicenseAccess {
+        self.shared.lock().access.unwrap_or(LicenseAccess::Denied)
+    }
+
+    /// Returns the result of the last check.
+    pub fn last_result(&self) -> Option<Result<LexActivatorStatus, LexActivatorError>> {
+        self.shared.lock().last.clone()
+    }
+
+    /// Stops the watchdog and waits for a running check to finish.
+    pub fn stop(mut self) {
+        self.thread.stop();
+    }
+}
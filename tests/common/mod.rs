@@ -0,0 +1,61 @@
+//! Helpers shared by the integration tests, which run against the `stub` feature.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use lexactivator::stub::{self, StubLicense};
+use lexactivator::*;
+
+pub const LICENSE_KEY: &str = "LICENSE-KEY";
+
+static STUB_LOCK: Mutex<()> = Mutex::new(());
+
+/// Serialises the tests of one test binary, as the stub state is global to the process, and resets the stub.
+pub fn lock() -> MutexGuard<'static, ()> {
+    let guard = STUB_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    stub::reset_state();
+    guard
+}
+
+/// Sets up the product and activates the given license.
+pub fn activate(license: StubLicense) {
+    stub::set_license(license);
+    set_product_data("PRODUCT_DATA".to_string()).unwrap();
+    set_product_id("PRODUCT_ID".to_string(), PermissionFlags::LA_USER).unwrap();
+    set_license_key(LICENSE_KEY.to_string()).unwrap();
+    assert_eq!(activate_license().unwrap(), LexActivatorStatus::LA_OK);
+}
+
+/// Returns the license used by [`activate`].
+pub fn license() -> StubLicense {
+    StubLicense::new(LICENSE_KEY)
+}
+
+/// Returns an empty directory that is unique to the calling test.
+pub fn temp_dir(name: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir().join(format!(
+        "lexactivator-{}-{}-{}",
+        name,
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Returns the license used by [`activate`] with a meter attribute.
+pub fn license_with_meter(name: &str, allowed_uses: i64, total_uses: u64) -> StubLicense {
+    let mut license = license();
+    license.meter_attributes.push(LicenseMeterAttribute {
+        name: name.to_string(),
+        allowed_uses,
+        total_uses,
+        gross_uses: total_uses,
+    });
+    license
+}
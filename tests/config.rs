@@ -0,0 +1,155 @@
+#![cfg(feature = "stub")]
+
+use std::sync::Mutex;
+
+use lexactivator::*;
+
+/// Records the setters called by a configuration, and fails the setter with the given name.
+#[derive(Debug, Default)]
+struct RecordingBackend {
+    calls: Mutex<Vec<&'static str>>,
+    failing: Option<&'static str>,
+}
+
+impl RecordingBackend {
+    fn failing(setter: &'static str) -> Self {
+        RecordingBackend { failing: Some(setter), ..RecordingBackend::default() }
+    }
+
+    fn record(&self, setter: &'static str) -> Result<(), LexActivatorError> {
+        self.calls.lock().unwrap().push(setter);
+        if self.failing == Some(setter) {
+            return Err(LexActivatorError::LA_E_FILE_PERMISSION);
+        }
+        Ok(())
+    }
+}
+
+impl LicensingBackend for RecordingBackend {
+    fn set_debug_mode(&self, _enable: u32) {
+        let _ = self.record("set_debug_mode");
+    }
+
+    fn set_product_data(&self, _product_data: String) -> Result<(), LexActivatorError> {
+        self.record("set_product_data")
+    }
+
+    fn set_product_id(&self, _product_id: String, _permission_flags: PermissionFlags) -> Result<(), LexActivatorError> {
+        self.record("set_product_id")
+    }
+
+    fn set_data_directory(&self, _data_dir: String) -> Result<(), LexActivatorError> {
+        self.record("set_data_directory")
+    }
+
+    fn set_cache_mode(&self, _mode: bool) -> Result<(), LexActivatorError> {
+        self.record("set_cache_mode")
+    }
+
+    fn set_network_proxy(&self, _proxy: String) -> Result<(), LexActivatorError> {
+        self.record("set_network_proxy")
+    }
+
+    fn set_release_version(&self, _version: String) -> Result<(), LexActivatorError> {
+        self.record("set_release_version")
+    }
+
+    fn set_activation_lease_duration(&self, _lease_duration: i64) -> Result<(), LexActivatorError> {
+        self.record("set_activation_lease_duration")
+    }
+
+    fn set_activation_metadata(&self, _key: String, _value: String) -> Result<(), LexActivatorError> {
+        self.record("set_activation_metadata")
+    }
+
+    fn set_trial_activation_metadata(&self, _key: String, _value: String) -> Result<(), LexActivatorError> {
+        self.record("set_trial_activation_metadata")
+    }
+
+    fn set_license_key(&self, _license_key: String) -> Result<(), LexActivatorError> {
+        self.record("set_license_key")
+    }
+}
+
+/// A configuration with settings in the reverse of the order they are applied in.
+fn config() -> LexActivatorConfig {
+    LexActivatorConfig::new("PRODUCT_DATA", "PRODUCT_ID")
+        .license_key("LICENSE_KEY")
+        .trial_activation_metadata("trial", "1")
+        .activation_metadata("seat", "1")
+        .activation_lease_duration(3600)
+        .release_version("1.2.0")
+        .network_proxy("http://proxy:3128")
+        .cache_mode(false)
+        .data_directory(std::env::temp_dir().to_string_lossy())
+        .debug_mode(true)
+}
+
+#[test]
+fn steps_are_applied_in_order() {
+    let lexactivator = config().build_with_backend(RecordingBackend::default()).unwrap();
+    let calls = lexactivator.backend().calls.lock().unwrap().clone();
+    assert_eq!(
+        calls,
+        [
+            "set_debug_mode",
+            "set_product_data",
+            "set_product_id",
+            "set_data_directory",
+            "set_cache_mode",
+            "set_network_proxy",
+            "set_release_version",
+            "set_activation_lease_duration",
+            "set_activation_metadata",
+            "set_trial_activation_metadata",
+            "set_license_key",
+        ]
+    );
+}
+
+#[test]
+fn a_failed_step_is_reported_and_stops_the_build() {
+    let error = config().build_with_backend(RecordingBackend::failing("set_data_directory")).unwrap_err();
+    match &error {
+        ConfigError::StepFailed { step, error } => {
+            assert_eq!(*step, ConfigStep::DataDirectory);
+            assert_eq!(*error, LexActivatorError::LA_E_FILE_PERMISSION);
+        }
+        error => panic!("expected a failed step, got {:?}", error),
+    }
+    assert_eq!(error.step(), &ConfigStep::DataDirectory);
+
+    let error = config().build_with_backend(RecordingBackend::failing("set_activation_metadata")).unwrap_err();
+    assert_eq!(error.step(), &ConfigStep::ActivationMetadata("seat".to_string()));
+    assert!(error.to_string().starts_with("set_activation_metadata(\"seat\") failed"));
+}
+
+#[test]
+fn missing_product_data_and_id_are_rejected() {
+    let error = LexActivatorConfig::new(" ", "PRODUCT_ID").validate().unwrap_err();
+    assert!(matches!(error, ConfigError::InvalidValue { step: ConfigStep::ProductData, .. }));
+
+    let error = LexActivatorConfig::new("PRODUCT_DATA", "").validate().unwrap_err();
+    assert!(matches!(error, ConfigError::InvalidValue { step: ConfigStep::ProductId, .. }));
+
+    let backend = RecordingBackend::default();
+    assert!(LexActivatorConfig::new("", "PRODUCT_ID").build_with_backend(backend).is_err());
+}
+
+#[test]
+fn duplicate_metadata_keys_are_rejected() {
+    let config = LexActivatorConfig::new("PRODUCT_DATA", "PRODUCT_ID").activation_metadata("seat", "1").activation_metadata("seat", "2");
+    match config.validate().unwrap_err() {
+        ConfigError::InvalidValue { step, reason } => {
+            assert_eq!(step, ConfigStep::ActivationMetadata("seat".to_string()));
+            assert_eq!(reason, "the metadata key is set more than once");
+        }
+        error => panic!("expected an invalid value, got {:?}", error),
+    }
+
+    let config = LexActivatorConfig::new("PRODUCT_DATA", "PRODUCT_ID").trial_activation_metadata("a", "1").trial_activation_metadata("a", "1");
+    assert_eq!(config.validate().unwrap_err().step(), &ConfigStep::TrialActivationMetadata("a".to_string()));
+
+    let config = LexActivatorConfig::new("PRODUCT_DATA", "PRODUCT_ID").activation_metadata("seat", "1").trial_activation_metadata("seat", "1");
+    assert!(config.validate().is_ok());
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use std::fs;
+use std::sync::Mutex;
+
+use lexactivator::*;
+
+/// Records the settings applied by a configuration.
+#[derive(Default)]
+struct RecordingBackend {
+    calls: Mutex<Vec<String>>,
+}
+
+impl RecordingBackend {
+    fn record(&self, call: String) -> Result<(), LexActivatorError> {
+        self.calls.lock().unwrap().push(call);
+        Ok(())
+    }
+}
+
+impl LicensingBackend for RecordingBackend {
+    fn set_product_data(&self, product_data: String) -> Result<(), LexActivatorError> {
+        self.record(format!("product_data={}", product_data))
+    }
+
+    fn set_product_id(&self, product_id: String, _permission_flags: PermissionFlags) -> Result<(), LexActivatorError> {
+        self.record(format!("product_id={}", product_id))
+    }
+
+    fn set_cache_mode(&self, mode: bool) -> Result<(), LexActivatorError> {
+        self.record(format!("cache_mode={}", mode))
+    }
+
+    fn set_network_proxy(&self, proxy: String) -> Result<(), LexActivatorError> {
+        self.record(format!("network_proxy={}", proxy))
+    }
+
+    fn set_release_published_date(&self, release_published_date: u32) -> Result<(), LexActivatorError> {
+        self.record(format!("release_published_date={}", release_published_date))
+    }
+}
+
+fn applied(config: LexActivatorConfig) -> Vec<String> {
+    let lexactivator = config.build_with_backend(RecordingBackend::default()).unwrap();
+    let calls = lexactivator.backend().calls.lock().unwrap().clone();
+    calls
+}
+
+fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+}
+
+fn write_config(name: &str, content: &str) -> std::path::PathBuf {
+    let path = common::temp_dir("config").join(name);
+    fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn environment_variables_override_the_file() {
+    let path = write_config(
+        "lexactivator.json",
+        r#"{"product_data": "FILE_DATA", "product_id": "FILE_ID", "network_proxy": "http://file:3128", "cache_mode": true}"#,
+    );
+    let env = vars(&[("LEXACTIVATOR_NETWORK_PROXY", "http://env:3128"), ("LEXACTIVATOR_RELEASE_PUBLISHED_DATE", "1700000000")]);
+
+    let calls = applied(LexActivatorConfig::load_from(Some(&path), env).unwrap());
+    assert!(calls.contains(&"product_data=FILE_DATA".to_string()));
+    assert!(calls.contains(&"cache_mode=true".to_string()));
+    assert!(calls.contains(&"network_proxy=http://env:3128".to_string()));
+    assert!(calls.contains(&"release_published_date=1700000000".to_string()));
+    assert!(!calls.contains(&"network_proxy=http://file:3128".to_string()));
+}
+
+#[test]
+fn build_script_and_unrelated_variables_are_ignored() {
+    let env = vars(&[
+        ("LEXACTIVATOR_PRODUCT_DATA", "ENV_DATA"),
+        ("LEXACTIVATOR_PRODUCT_ID", "ENV_ID"),
+        ("LEXACTIVATOR_LIB_DIR", "/opt/lexactivator"),
+        ("LEXACTIVATOR_ARCHIVE", "/opt/LexActivator-Static-Linux.zip"),
+        ("LEXACTIVATOR_ARCHIVE_SHA256", "0000"),
+        ("PATH", "/usr/bin"),
+    ]);
+
+    let calls = applied(LexActivatorConfig::load_from(None, env).unwrap());
+    assert_eq!(calls, ["product_data=ENV_DATA", "product_id=ENV_ID"]);
+}
+
+#[test]
+fn misspelt_environment_variables_are_an_error() {
+    for (name, key) in [("LEXACTIVATOR_PRODUCTID", "productid"), ("LEXACTIVATOR_PROXY", "proxy")] {
+        let env = vars(&[("LEXACTIVATOR_PRODUCT_DATA", "DATA"), ("LEXACTIVATOR_PRODUCT_ID", "ID"), (name, "value")]);
+        match LexActivatorConfig::load_from(None, env) {
+            Err(ConfigLoadError::UnknownKey { origin, key: unknown }) => {
+                assert_eq!(origin, ConfigOrigin::Env(name.to_string()));
+                assert_eq!(unknown, key);
+            }
+            result => panic!("expected an unknown key error, got {:?}", result),
+        }
+    }
+}
+
+#[test]
+fn unknown_file_keys_are_an_error() {
+    let path = write_config("lexactivator.json", r#"{"product_data": "DATA", "product_id": "ID", "proxy": "http://file:3128"}"#);
+
+    match LexActivatorConfig::load_from(Some(&path), Vec::new()) {
+        Err(ConfigLoadError::UnknownKey { origin, key }) => {
+            assert_eq!(origin, ConfigOrigin::File(path));
+            assert_eq!(key, "proxy");
+        }
+        result => panic!("expected an unknown key error, got {:?}", result),
+    }
+}
+
+#[test]
+fn invalid_and_missing_values_are_errors() {
+    let env = vars(&[("LEXACTIVATOR_PRODUCT_DATA", "DATA"), ("LEXACTIVATOR_PRODUCT_ID", "ID"), ("LEXACTIVATOR_CACHE_MODE", "sometimes")]);
+    match LexActivatorConfig::load_from(None, env) {
+        Err(ConfigLoadError::InvalidValue { origin, key, .. }) => {
+            assert_eq!(origin, ConfigOrigin::Env("LEXACTIVATOR_CACHE_MODE".to_string()));
+            assert_eq!(key, "cache_mode");
+        }
+        result => panic!("expected an invalid value error, got {:?}", result),
+    }
+
+    let env = vars(&[("LEXACTIVATOR_PRODUCT_DATA", "DATA")]);
+    assert!(matches!(LexActivatorConfig::load_from(None, env), Err(ConfigLoadError::MissingKey { key: "product_id" })));
+}
+
+#[cfg(unix)]
+#[test]
+fn non_utf8_environment_values_are_invalid_values() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let _guard = common::lock();
+    std::env::set_var("LEXACTIVATOR_NETWORK_PROXY", OsStr::from_bytes(b"http://\xff:3128"));
+    let result = LexActivatorConfig::load(None);
+    std::env::remove_var("LEXACTIVATOR_NETWORK_PROXY");
+
+    match result {
+        Err(ConfigLoadError::InvalidValue { origin, key, .. }) => {
+            assert_eq!(origin, ConfigOrigin::Env("LEXACTIVATOR_NETWORK_PROXY".to_string()));
+            assert_eq!(key, "network_proxy");
+        }
+        result => panic!("expected an invalid value error, got {:?}", result),
+    }
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "stub")]
+
+use std::error::Error;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use lexactivator::*;
+
+fn entitlement(value: &str, base_value: &str) -> FeatureEntitlement {
+    FeatureEntitlement {
+        feature_name: "feature".to_string(),
+        feature_display_name: "Feature".to_string(),
+        value: value.to_string(),
+        baseValue: base_value.to_string(),
+        expires_at: 0,
+    }
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64
+}
+
+#[test]
+fn booleans_are_parsed_in_any_case() {
+    for (value, expected) in [("true", true), ("TRUE", true), ("True", true), (" false ", false), ("FaLsE", false)] {
+        assert_eq!(entitlement(value, "").value_as_bool().unwrap(), expected, "{:?}", value);
+    }
+    for value in ["1", "0", "yes", ""] {
+        let error = entitlement(value, "").value_as_bool().unwrap_err();
+        assert_eq!(error.expected(), FeatureValueType::Bool);
+        assert_eq!(error.value(), value);
+        assert_eq!(error.feature_name(), "feature");
+    }
+    assert!(entitlement("false", "tRuE").base_value_as_bool().unwrap());
+}
+
+#[test]
+fn integers_are_parsed_within_range() {
+    assert_eq!(entitlement(" 42 ", "").value_as_i64().unwrap(), 42);
+    assert_eq!(entitlement(&i64::MIN.to_string(), "").value_as_i64().unwrap(), i64::MIN);
+    assert_eq!(entitlement("", "-7").base_value_as_i64().unwrap(), -7);
+    for value in ["9223372036854775808", "-9223372036854775809", "1.5", "ten"] {
+        let error = entitlement(value, "").value_as_i64().unwrap_err();
+        assert_eq!(error.expected(), FeatureValueType::Integer, "{:?}", value);
+    }
+}
+
+#[test]
+fn decimals_must_be_finite() {
+    assert_eq!(entitlement("2.5", "").value_as_f64().unwrap(), 2.5);
+    assert_eq!(entitlement("", "1e3").base_value_as_f64().unwrap(), 1000.0);
+    for value in ["NaN", "inf", "-infinity", "1e400", "two"] {
+        let error = entitlement(value, "").value_as_f64().unwrap_err();
+        assert_eq!(error.expected(), FeatureValueType::Decimal, "{:?}", value);
+    }
+}
+
+#[test]
+fn json_errors_keep_the_serde_error() {
+    let value: serde_json::Value = entitlement(r#"{"seats": 5}"#, "").value_as_json().unwrap();
+    assert_eq!(value["seats"], 5);
+    let base: Vec<u32> = entitlement("", "[1, 2]").base_value_as_json().unwrap();
+    assert_eq!(base, [1, 2]);
+
+    let error = entitlement(r#"{"seats": "#, "").value_as_json::<serde_json::Value>().unwrap_err();
+    assert_eq!(error.expected(), FeatureValueType::Json);
+    let source = error.source().expect("the serde_json error is the source");
+    assert!(source.downcast_ref::<serde_json::Error>().unwrap().is_eof());
+    assert!(error.to_string().contains(&source.to_string()));
+
+    assert!(entitlement("maybe", "").value_as_bool().unwrap_err().source().is_none());
+}
+
+#[test]
+fn entitlements_without_an_expiry_date_do_not_expire() {
+    let entitlement = entitlement("true", "true");
+    assert_eq!(entitlement.expires_at, 0);
+    assert!(!entitlement.is_expired());
+}
+
+#[test]
+fn entitlements_expire_once_the_date_has_passed() {
+    let mut entitlement = entitlement("true", "true");
+    entitlement.expires_at = unix_now() - 60;
+    assert!(entitlement.is_expired());
+
+    entitlement.expires_at = unix_now() + 3600;
+    assert!(!entitlement.is_expired());
+}
+
+#[test]
+fn overrides_are_detected() {
+    assert!(!entitlement("5", "5").is_overridden());
+    assert!(entitlement("10", "5").is_overridden());
+    assert!(entitlement("10", "").is_overridden());
+    assert_eq!(entitlement("10", "").base_value(), "");
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "stub")]
+
+use lexactivator::*;
+
+#[test]
+fn unknown_status_codes_are_kept() {
+    assert_eq!(LexActivatorStatus::from(23), LexActivatorStatus::Unknown(23));
+    assert_eq!(LexActivatorStatus::Unknown(23).code(), 23);
+    assert_eq!(LexActivatorStatus::from(LexActivatorStatus::Unknown(23).code()), LexActivatorStatus::Unknown(23));
+    assert_eq!(LexActivatorStatus::from(22), LexActivatorStatus::LA_GRACE_PERIOD_OVER);
+}
+
+#[test]
+fn unknown_error_codes_are_kept() {
+    assert_eq!(LexActivatorError::from(999), LexActivatorError::Unknown(999));
+    assert_eq!(LexActivatorError::Unknown(999).code(), 999);
+    assert_eq!(LexActivatorError::from(LexActivatorError::Unknown(999).code()), LexActivatorError::Unknown(999));
+    assert_eq!(LexActivatorError::from(48), LexActivatorError::LA_E_INET);
+    assert_eq!(LexActivatorCode::from_i32(999), LexActivatorCode::Error(LexActivatorError::Unknown(999)));
+    assert_eq!(LexActivatorCode::from_i32(23), LexActivatorCode::Status(LexActivatorStatus::Unknown(23)));
+}
+
+#[test]
+fn errors_of_the_crate_have_their_own_codes() {
+    let not_implemented = LexActivatorError::NotImplemented("get_license_key");
+    assert_eq!(not_implemented.code(), LexActivatorError::NOT_IMPLEMENTED_CODE);
+    assert_ne!(LexActivatorError::JSON_PARSE_CODE, LexActivatorError::NOT_IMPLEMENTED_CODE);
+
+    for code in [LexActivatorError::JSON_PARSE_CODE, LexActivatorError::NOT_IMPLEMENTED_CODE] {
+        assert!(code < 0);
+        assert_eq!(LexActivatorError::from(code), LexActivatorError::Unknown(code));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn errors_of_the_crate_are_not_deserialized_as_library_errors() {
+    let json = serde_json::to_string(&LexActivatorError::NotImplemented("get_license_key")).unwrap();
+    let error: LexActivatorError = serde_json::from_str(&json).unwrap();
+    assert_eq!(error, LexActivatorError::Unknown(LexActivatorError::NOT_IMPLEMENTED_CODE));
+
+    let json = serde_json::to_string(&LexActivatorError::Unknown(999)).unwrap();
+    assert_eq!(serde_json::from_str::<LexActivatorError>(&json).unwrap(), LexActivatorError::Unknown(999));
+}
+
+#[test]
+fn error_predicates_classify_the_codes() {
+    use LexActivatorError::*;
+
+    // (error, is_network_related, is_retryable, requires_user_action)
+    let cases = [
+        (LA_E_INET, true, true, false),
+        (LA_E_SERVER, true, true, false),
+        (LA_E_RATE_LIMIT, true, true, false),
+        (LA_E_NET_PROXY, true, false, true),
+        (LA_E_HOST_URL, true, false, false),
+        (LA_E_LOGIN_TEMPORARILY_LOCKED, false, true, true),
+        (LA_E_SYSTEM_PERMISSION, false, false, true),
+        (LA_E_FILE_PERMISSION, false, false, true),
+        (LA_E_WMIC, false, false, true),
+        (LA_E_TIME, false, false, true),
+        (LA_E_LICENSE_KEY, false, false, true),
+        (LA_E_OFFLINE_RESPONSE_FILE, false, false, true),
+        (LA_E_OFFLINE_RESPONSE_FILE_EXPIRED, false, false, true),
+        (LA_E_ACTIVATION_LIMIT, false, false, true),
+        (LA_E_ACTIVATION_NOT_FOUND, false, false, true),
+        (LA_E_DEACTIVATION_LIMIT, false, false, true),
+        (LA_E_MACHINE_FINGERPRINT, false, false, true),
+        (LA_E_TIME_MODIFIED, false, false, true),
+        (LA_E_AUTHENTICATION_FAILED, false, false, true),
+        (LA_E_USER_NOT_AUTHENTICATED, false, false, true),
+        (LA_E_TWO_FACTOR_AUTHENTICATION_CODE_MISSING, false, false, true),
+        (LA_E_TWO_FACTOR_AUTHENTICATION_CODE_INVALID, false, false, true),
+        (LA_E_AUTHENTICATION_ID_TOKEN_INVALID, false, false, true),
+        (LA_E_OS_USER, false, false, true),
+        (LA_FAIL, false, false, false),
+        (LA_E_PRODUCT_ID, false, false, false),
+        (LA_E_REVOKED, false, false, false),
+        (LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED, false, false, false),
+        (LA_E_BUFFER_SIZE, false, false, false),
+        (LA_E_CLIENT, false, false, false),
+        (Unknown(999), false, false, false),
+        (NotImplemented("is_license_genuine"), false, false, false),
+    ];
+    for (error, network_related, retryable, user_action) in cases {
+        assert_eq!(error.is_network_related(), network_related, "is_network_related of {:?}", error);
+        assert_eq!(error.is_retryable(), retryable, "is_retryable of {:?}", error);
+        assert_eq!(error.requires_user_action(), user_action, "requires_user_action of {:?}", error);
+    }
+}
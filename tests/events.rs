@@ -0,0 +1,199 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use lexactivator::*;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Invokes the license callback on another thread and fails the test if it does not return, e.g. because of
+/// a deadlock.
+fn invoke_license_callback(code: i32) {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        stub::invoke_license_callback(code);
+        let _ = sender.send(());
+    });
+    receiver.recv_timeout(TIMEOUT).expect("the license callback did not return");
+}
+
+/// Records the panic messages reported to the panic handler.
+fn record_panics() -> Arc<Mutex<Vec<String>>> {
+    let panics = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&panics);
+    set_license_callback_panic_handler(move |message| recorded.lock().unwrap().push(message));
+    panics
+}
+
+/// Panics when the last clone is dropped, to poison the lock that is held while it is dropped.
+struct PanicOnDrop;
+
+impl Drop for PanicOnDrop {
+    fn drop(&mut self) {
+        if !thread::panicking() {
+            panic!("dropped");
+        }
+    }
+}
+
+#[test]
+fn codes_are_mapped_to_events() {
+    let status = |status: LexActivatorStatus| LexActivatorCode::Status(status);
+    let error = |error: LexActivatorError| LexActivatorCode::Error(error);
+    let cases = [
+        (status(LexActivatorStatus::LA_OK), LicenseEvent::Valid),
+        (status(LexActivatorStatus::LA_GRACE_PERIOD_OVER), LicenseEvent::GracePeriodOver),
+        (status(LexActivatorStatus::LA_EXPIRED), LicenseEvent::Expired),
+        (status(LexActivatorStatus::LA_SUSPENDED), LicenseEvent::Suspended),
+        (error(LexActivatorError::LA_E_REVOKED), LicenseEvent::Revoked),
+        (error(LexActivatorError::LA_E_ACTIVATION_NOT_FOUND), LicenseEvent::ActivationDeleted),
+        (error(LexActivatorError::LA_E_MACHINE_FINGERPRINT), LicenseEvent::FingerprintChanged),
+        (error(LexActivatorError::LA_E_INET), LicenseEvent::TransientNetworkFailure(LexActivatorError::LA_E_INET)),
+        (error(LexActivatorError::LA_E_SERVER), LicenseEvent::TransientNetworkFailure(LexActivatorError::LA_E_SERVER)),
+        (error(LexActivatorError::LA_E_RATE_LIMIT), LicenseEvent::TransientNetworkFailure(LexActivatorError::LA_E_RATE_LIMIT)),
+        (status(LexActivatorStatus::LA_TRIAL_EXPIRED), LicenseEvent::Other(status(LexActivatorStatus::LA_TRIAL_EXPIRED))),
+        (error(LexActivatorError::LA_E_TIME), LicenseEvent::Other(error(LexActivatorError::LA_E_TIME))),
+    ];
+    for (code, event) in cases {
+        let numeric = code.code();
+        assert_eq!(LicenseEvent::from(LexActivatorCode::from_i32(numeric)), event, "code {}", numeric);
+        assert_eq!(event.code(), numeric);
+    }
+}
+
+#[test]
+fn subscribers_receive_events_until_the_subscription_is_dropped() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let (code_sender, codes) = mpsc::channel();
+    let (event_sender, events) = mpsc::channel();
+    let channel = subscribe_license_channel(code_sender).unwrap();
+    let closure = subscribe_license_events(move |event| event_sender.send(event).unwrap()).unwrap();
+
+    invoke_license_callback(LexActivatorStatus::LA_GRACE_PERIOD_OVER.code());
+    assert_eq!(codes.try_recv().unwrap(), LexActivatorCode::Status(LexActivatorStatus::LA_GRACE_PERIOD_OVER));
+    assert_eq!(events.try_recv().unwrap(), LicenseEvent::GracePeriodOver);
+
+    drop(channel);
+    drop(closure);
+    invoke_license_callback(LexActivatorStatus::LA_OK.code());
+    assert!(codes.try_recv().is_err());
+    assert!(events.try_recv().is_err());
+}
+
+#[test]
+fn callbacks_can_replace_and_unset_themselves() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let (sender, receiver) = mpsc::channel();
+
+    let first = sender.clone();
+    set_license_callback(move |code| {
+        first.send(("first", code)).unwrap();
+        let replaced = first.clone();
+        set_license_callback(move |code| {
+            replaced.send(("replaced", code)).unwrap();
+            unset_license_callback();
+        })
+        .unwrap();
+    })
+    .unwrap();
+
+    invoke_license_callback(LexActivatorStatus::LA_OK.code());
+    invoke_license_callback(LexActivatorStatus::LA_EXPIRED.code());
+    invoke_license_callback(LexActivatorStatus::LA_OK.code());
+    drop(sender);
+    let calls: Vec<_> = receiver.iter().collect();
+    assert_eq!(
+        calls,
+        [
+            ("first", LexActivatorCode::Status(LexActivatorStatus::LA_OK)),
+            ("replaced", LexActivatorCode::Status(LexActivatorStatus::LA_EXPIRED)),
+        ]
+    );
+}
+
+#[test]
+fn subscribers_can_subscribe_and_unsubscribe_from_the_callback() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let (sender, receiver) = mpsc::channel();
+    let nested = Arc::new(Mutex::new(None));
+
+    let subscribe = Arc::clone(&nested);
+    let outer = subscribe_license_callback(move |_| {
+        let mut nested = subscribe.lock().unwrap();
+        // Subscribes on the first code and unsubscribes on the second.
+        match nested.take() {
+            None => *nested = Some(subscribe_license_channel(sender.clone()).unwrap()),
+            Some(subscription) => drop(subscription),
+        }
+    })
+    .unwrap();
+
+    invoke_license_callback(LexActivatorStatus::LA_OK.code());
+    invoke_license_callback(LexActivatorStatus::LA_EXPIRED.code());
+    invoke_license_callback(LexActivatorStatus::LA_SUSPENDED.code());
+    assert_eq!(receiver.try_recv().unwrap(), LexActivatorCode::Status(LexActivatorStatus::LA_EXPIRED));
+    assert!(receiver.try_recv().is_err());
+    drop(outer);
+    nested.lock().unwrap().take();
+}
+
+#[test]
+fn panicking_subscribers_do_not_stop_the_others() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let panics = record_panics();
+    let (sender, receiver) = mpsc::channel();
+
+    let first = subscribe_license_callback(|_| panic!("subscriber failed")).unwrap();
+    let second = subscribe_license_channel(sender.clone()).unwrap();
+    set_license_callback(|_| panic!("callback failed")).unwrap();
+    let third = subscribe_license_channel(sender).unwrap();
+
+    invoke_license_callback(LexActivatorStatus::LA_OK.code());
+    assert_eq!(receiver.try_iter().count(), 2);
+    assert_eq!(*panics.lock().unwrap(), ["subscriber failed", "callback failed"]);
+
+    unset_license_callback();
+    drop((first, second, third));
+    set_license_callback_panic_handler(|_| {});
+}
+
+#[test]
+fn codes_are_dispatched_after_a_lock_was_poisoned() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let _panics = record_panics();
+
+    let guard = Arc::new(PanicOnDrop);
+    let subscription = subscribe_license_callback(move |_| {
+        let _ = &guard;
+    })
+    .unwrap();
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| drop(subscription))).is_err());
+
+    let guard = Arc::new(PanicOnDrop);
+    set_license_callback(move |_| {
+        let _ = &guard;
+    })
+    .unwrap();
+    assert!(panic::catch_unwind(|| set_license_callback(|_| {})).is_err());
+
+    let (sender, receiver) = mpsc::channel();
+    let subscription = subscribe_license_channel(sender.clone()).unwrap();
+    set_license_callback(move |code| sender.send(code).unwrap()).unwrap();
+    invoke_license_callback(LexActivatorStatus::LA_OK.code());
+    assert_eq!(receiver.try_iter().count(), 2);
+
+    unset_license_callback();
+    drop(subscription);
+    set_license_callback_panic_handler(|_| {});
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use lexactivator::stub::StubLicense;
+use lexactivator::*;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn license_expiring_in(duration: Duration) -> StubLicense {
+    let mut license = common::license();
+    license.expiry_date = (SystemTime::now() + duration).duration_since(UNIX_EPOCH).unwrap().as_secs() as u32;
+    license
+}
+
+fn open(directory: &Path) -> ExpiryWarnings {
+    ExpiryWarnings::builder(directory)
+        .thresholds([30 * DAY, 7 * DAY, DAY])
+        .deadlines([ExpiryDeadline::LicenseExpiry])
+        .open()
+        .unwrap()
+}
+
+#[test]
+fn only_the_closest_crossed_threshold_is_warned_about() {
+    let _guard = common::lock();
+    common::activate(license_expiring_in(5 * DAY));
+    let warnings = open(&common::temp_dir("expiry-warnings")).check().unwrap();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].deadline, ExpiryDeadline::LicenseExpiry);
+    assert_eq!(warnings[0].threshold, 7 * DAY);
+    assert!(warnings[0].time_remaining <= 5 * DAY);
+}
+
+#[test]
+fn delivered_warnings_are_not_repeated_after_a_restart() {
+    let _guard = common::lock();
+    common::activate(license_expiring_in(5 * DAY));
+    let directory = common::temp_dir("expiry-warnings");
+
+    assert_eq!(open(&directory).check().unwrap().len(), 1);
+    assert!(directory.join(EXPIRY_WARNINGS_FILE_NAME).exists());
+    assert!(open(&directory).check().unwrap().is_empty());
+}
+
+#[test]
+fn a_changed_expiry_date_is_warned_about_again() {
+    let _guard = common::lock();
+    common::activate(license_expiring_in(5 * DAY));
+    let warnings = open(&common::temp_dir("expiry-warnings"));
+    assert_eq!(warnings.check().unwrap().len(), 1);
+
+    stub::update_license(|license| license.expiry_date += 60);
+    assert_eq!(warnings.check().unwrap().len(), 1);
+    assert!(warnings.check().unwrap().is_empty());
+}
+
+#[test]
+fn delivered_warnings_are_kept_while_the_expiry_date_cannot_be_read() {
+    let _guard = common::lock();
+    let license = license_expiring_in(5 * DAY);
+    common::activate(license.clone());
+    let directory = common::temp_dir("expiry-warnings");
+    assert_eq!(open(&directory).check().unwrap().len(), 1);
+
+    stub::reset_state();
+    assert!(get_license_expiry_time().is_err());
+    assert!(open(&directory).check().unwrap().is_empty());
+
+    common::activate(license);
+    assert!(open(&directory).check().unwrap().is_empty());
+}
+
+#[test]
+fn passed_expiry_dates_are_not_warned_about() {
+    let _guard = common::lock();
+    let mut license = common::license();
+    license.expiry_date = 1;
+    stub::set_license(license);
+    set_product_data("PRODUCT_DATA".to_string()).unwrap();
+    set_product_id("PRODUCT_ID".to_string(), PermissionFlags::LA_USER).unwrap();
+    set_license_key(common::LICENSE_KEY.to_string()).unwrap();
+    let _ = activate_license();
+
+    let directory = common::temp_dir("expiry-warnings");
+    assert!(open(&directory).check().unwrap().is_empty());
+    assert!(!directory.join(EXPIRY_WARNINGS_FILE_NAME).exists());
+}
+
+#[test]
+fn an_invalid_file_is_reported() {
+    let directory = common::temp_dir("expiry-warnings");
+    std::fs::write(directory.join(EXPIRY_WARNINGS_FILE_NAME), "not json").unwrap();
+
+    let error = ExpiryWarnings::builder(&directory).threshold(DAY).open().unwrap_err();
+    assert!(matches!(error, ExpiryWarningsError::StateFile(StateFileError::Parse { .. })));
+}
+
+#[test]
+fn thresholds_are_required() {
+    let error = ExpiryWarnings::builder(common::temp_dir("expiry-warnings")).open().unwrap_err();
+    assert!(matches!(error, ExpiryWarningsError::NoThresholds));
+}
+
+#[test]
+fn the_scheduler_reports_errors_of_storing_the_warnings() {
+    let _guard = common::lock();
+    common::activate(license_expiring_in(5 * DAY));
+    let directory = common::temp_dir("expiry-warnings").join("missing");
+    let (warning_sender, warning_receiver) = mpsc::channel();
+    let (error_sender, error_receiver) = mpsc::channel();
+
+    let scheduler = open(&directory)
+        .schedule(
+            Duration::from_secs(60 * 60),
+            move |warning| warning_sender.send(warning.clone()).unwrap(),
+            move |error| error_sender.send(error).unwrap(),
+        )
+        .unwrap();
+    scheduler.stop();
+
+    assert!(matches!(error_receiver.try_recv().unwrap(), StateFileError::Io { .. }));
+    assert!(warning_receiver.try_recv().is_err());
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use lexactivator::*;
+
+const ENTITLEMENTS: &str = r#"[{"featureName": "export", "featureDisplayName": "Export", "value": "true", "expiresAt": 0},
+    {"featureName": "max-projects", "featureDisplayName": "Max projects", "value": "10", "expiresAt": 0}]"#;
+
+fn entitled_license() -> stub::StubLicense {
+    let mut license = common::license();
+    license.entitlement_set_name = "pro".to_string();
+    license.feature_entitlements_json = ENTITLEMENTS.to_string();
+    license
+}
+
+fn gate() -> FeatureGate {
+    FeatureGate::builder().default_enabled("export", false).default_limit("max-projects", 3).build().unwrap()
+}
+
+fn wait_until(condition: impl Fn() -> bool) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    false
+}
+
+#[test]
+fn defaults_apply_before_activation() {
+    let _guard = common::lock();
+    stub::set_license(entitled_license());
+    set_product_data("PRODUCT_DATA".to_string()).unwrap();
+    set_product_id("PRODUCT_ID".to_string(), PermissionFlags::LA_USER).unwrap();
+
+    let features = gate();
+    assert!(!features.is_enabled("export"));
+    assert_eq!(features.limit("max-projects"), Some(3));
+}
+
+#[test]
+fn entitlements_are_reloaded_on_sync() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let features = gate();
+    assert_eq!(features.limit("max-projects"), Some(3));
+
+    stub::set_license(entitled_license());
+    stub::simulate_server_sync();
+    assert!(wait_until(|| features.limit("max-projects") == Some(10)));
+    assert!(features.is_enabled("export"));
+}
+
+#[test]
+fn entitlements_are_dropped_when_the_license_is_revoked() {
+    let _guard = common::lock();
+    common::activate(entitled_license());
+    let features = gate();
+    assert_eq!(features.limit("max-projects"), Some(10));
+
+    stub::invoke_license_callback(LexActivatorError::LA_E_REVOKED.code());
+    assert_eq!(features.limit("max-projects"), Some(3));
+    assert!(!features.is_enabled("export"));
+
+    features.refresh().unwrap();
+    assert_eq!(features.limit("max-projects"), Some(10));
+}
+
+#[test]
+fn entitlements_are_kept_on_network_failures() {
+    let _guard = common::lock();
+    common::activate(entitled_license());
+    let features = gate();
+
+    stub::invoke_license_callback(LexActivatorError::LA_E_INET.code());
+    assert_eq!(features.limit("max-projects"), Some(10));
+}
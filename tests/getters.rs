@@ -0,0 +1,91 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use std::error::Error;
+
+use lexactivator::*;
+
+#[test]
+fn invalid_feature_entitlements_json_is_a_json_parse_error() {
+    let _guard = common::lock();
+    common::activate(license_with_entitlements("[{\"featureName\": "));
+
+    let error = get_feature_entitlements().unwrap_err();
+    let LexActivatorError::JsonParse(parse_error) = &error else {
+        panic!("expected a JSON parse error, got {:?}", error);
+    };
+    assert_eq!(parse_error.raw_json(), "[{\"featureName\": ");
+    assert!(parse_error.serde_error().is_eof());
+    assert_eq!(error.code(), LexActivatorError::JSON_PARSE_CODE);
+    assert!(error.source().is_some());
+}
+
+#[test]
+fn invalid_organization_address_json_is_a_json_parse_error() {
+    let _guard = common::lock();
+    let mut license = common::license();
+    license.organization_address_json = "{\"city\": 42}".to_string();
+    common::activate(license);
+
+    match get_license_organization_address() {
+        Err(LexActivatorError::JsonParse(parse_error)) => assert_eq!(parse_error.raw_json(), "{\"city\": 42}"),
+        result => panic!("expected a JSON parse error, got {:?}", result),
+    }
+}
+
+#[test]
+fn unknown_json_fields_are_ignored() {
+    let _guard = common::lock();
+    common::activate(license_with_entitlements(
+        r#"[{"featureName": "export", "featureDisplayName": "Export", "value": "true", "expiresAt": 0, "addedLater": 1}]"#,
+    ));
+
+    let entitlements = get_feature_entitlements().unwrap();
+    assert_eq!(entitlements.len(), 1);
+    assert_eq!(entitlements[0].feature_name, "export");
+    assert_eq!(get_feature_entitlement("export".to_string()).unwrap().value, "true");
+}
+
+#[test]
+fn string_getters_grow_the_buffer_for_long_values() {
+    let _guard = common::lock();
+    let long_value = "v".repeat(4096);
+    let mut license = common::license();
+    license.metadata.insert("notes".to_string(), long_value.clone());
+    license.user_name = "ü".repeat(300);
+    common::activate(license);
+
+    assert_eq!(get_license_metadata("notes".to_string()).unwrap(), long_value);
+    assert_eq!(get_license_user_name().unwrap(), "ü".repeat(300));
+}
+
+#[test]
+fn json_getters_grow_the_buffer_for_long_documents() {
+    let _guard = common::lock();
+    let entitlements: Vec<String> = (0..200)
+        .map(|i| format!(r#"{{"featureName": "feature-{i}", "featureDisplayName": "Feature {i}", "value": "{i}", "expiresAt": 0}}"#))
+        .collect();
+    common::activate(license_with_entitlements(&format!("[{}]", entitlements.join(","))));
+
+    let entitlements = get_feature_entitlements().unwrap();
+    assert_eq!(entitlements.len(), 200);
+    assert_eq!(entitlements[199].feature_name, "feature-199");
+}
+
+#[test]
+fn string_getters_give_up_above_the_maximum_buffer_length() {
+    let _guard = common::lock();
+    let mut license = common::license();
+    license.metadata.insert("huge".to_string(), "v".repeat(2 * 1024 * 1024));
+    common::activate(license);
+
+    assert_eq!(get_license_metadata("huge".to_string()), Err(LexActivatorError::LA_E_BUFFER_SIZE));
+}
+
+fn license_with_entitlements(json: &str) -> stub::StubLicense {
+    let mut license = common::license();
+    license.entitlement_set_name = "pro".to_string();
+    license.feature_entitlements_json = json.to_string();
+    license
+}
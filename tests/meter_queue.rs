@@ -0,0 +1,126 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use lexactivator::*;
+
+fn operation(name: &str, kind: MeterOperationKind, uses: u32) -> MeterOperation {
+    MeterOperation { name: name.to_string(), kind, uses }
+}
+
+fn total_uses(name: &str) -> u64 {
+    get_license_meterattribute(name.to_string()).unwrap().total_uses
+}
+
+#[test]
+fn operations_are_sent_directly_while_online() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("exports", 10, 0));
+    let directory = common::temp_dir("meter-queue");
+    let queue = MeterQueue::open(&directory).unwrap();
+
+    queue.increment("exports", 2).unwrap();
+    assert!(queue.is_empty());
+    assert_eq!(total_uses("exports"), 2);
+    assert!(!directory.join(METER_QUEUE_FILE_NAME).exists());
+}
+
+#[test]
+fn offline_operations_are_persisted_and_replayed() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("exports", 10, 0));
+    let directory = common::temp_dir("meter-queue");
+    stub::set_network_available(false);
+
+    let queue = MeterQueue::open(&directory).unwrap();
+    queue.increment("exports", 2).unwrap();
+    queue.increment("exports", 3).unwrap();
+    queue.decrement("exports", 1).unwrap();
+    let pending = [operation("exports", MeterOperationKind::Increment, 5), operation("exports", MeterOperationKind::Decrement, 1)];
+    assert_eq!(queue.pending(), pending);
+    drop(queue);
+
+    let queue = MeterQueue::open(&directory).unwrap();
+    assert_eq!(queue.pending(), pending);
+    let report = queue.replay().unwrap();
+    assert!(report.replayed.is_empty());
+    assert_eq!(report.pending, 2);
+
+    stub::set_network_available(true);
+    let report = queue.replay().unwrap();
+    assert_eq!(report.replayed, pending);
+    assert!(report.dropped.is_empty());
+    assert_eq!(report.pending, 0);
+    assert_eq!(total_uses("exports"), 4);
+    assert!(!directory.join(METER_QUEUE_FILE_NAME).exists());
+}
+
+#[test]
+fn only_the_uses_beyond_the_limit_are_dropped() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("exports", 3, 0));
+    let queue = MeterQueue::open(common::temp_dir("meter-queue")).unwrap();
+    stub::set_network_available(false);
+    queue.increment("exports", 2).unwrap();
+    queue.increment("exports", 2).unwrap();
+    assert_eq!(queue.pending(), [operation("exports", MeterOperationKind::Increment, 4)]);
+
+    stub::set_network_available(true);
+    let report = queue.replay().unwrap();
+    assert_eq!(total_uses("exports"), 3);
+    assert_eq!(report.replayed.iter().map(|operation| operation.uses).sum::<u32>(), 3);
+    assert_eq!(
+        report.dropped,
+        [(operation("exports", MeterOperationKind::Increment, 1), LexActivatorError::LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED)]
+    );
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn operations_on_unknown_attributes_are_dropped() {
+    let _guard = common::lock();
+    let mut license = common::license_with_meter("exports", 10, 0);
+    license.meter_attributes.extend(common::license_with_meter("imports", 10, 0).meter_attributes);
+    common::activate(license);
+    let queue = MeterQueue::open(common::temp_dir("meter-queue")).unwrap();
+    stub::set_network_available(false);
+    queue.increment("imports", 2).unwrap();
+    queue.increment("exports", 1).unwrap();
+
+    stub::update_license(|license| license.meter_attributes.retain(|attribute| attribute.name != "imports"));
+    stub::set_network_available(true);
+    let report = queue.replay().unwrap();
+    assert_eq!(report.replayed, [operation("exports", MeterOperationKind::Increment, 1)]);
+    assert_eq!(
+        report.dropped,
+        [(operation("imports", MeterOperationKind::Increment, 2), LexActivatorError::LA_E_METER_ATTRIBUTE_NOT_FOUND)]
+    );
+}
+
+#[test]
+fn operations_are_not_sent_if_the_queue_cannot_be_saved() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("exports", 10, 0));
+    let directory = common::temp_dir("meter-queue");
+    stub::set_network_available(false);
+
+    let queue = MeterQueue::open(&directory).unwrap();
+    queue.increment("exports", 2).unwrap();
+    queue.decrement("exports", 1).unwrap();
+    let pending = queue.pending();
+    let saved = std::fs::read(directory.join(METER_QUEUE_FILE_NAME)).unwrap();
+
+    // The queue file is written through a temporary file, which cannot be created over a directory.
+    let temporary = directory.join(METER_QUEUE_FILE_NAME).with_extension("json.tmp");
+    std::fs::create_dir(&temporary).unwrap();
+    stub::set_network_available(true);
+    assert!(matches!(queue.replay(), Err(MeterQueueError::StateFile(_))));
+    assert_eq!(queue.pending(), pending);
+    assert_eq!(std::fs::read(directory.join(METER_QUEUE_FILE_NAME)).unwrap(), saved);
+    assert_eq!(total_uses("exports"), 0);
+
+    std::fs::remove_dir(&temporary).unwrap();
+    let report = queue.replay().unwrap();
+    assert_eq!(report.replayed, pending);
+    assert_eq!(total_uses("exports"), 1);
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use lexactivator::*;
+
+fn quota(allowed_uses: i64, total_uses: u64, activation_uses: u32) -> MeterQuota {
+    let attribute = LicenseMeterAttribute { name: "exports".to_string(), allowed_uses, total_uses, gross_uses: total_uses };
+    MeterQuota::new(attribute, activation_uses)
+}
+
+#[test]
+fn limited_quotas() {
+    let quota = quota(10, 4, 1);
+    assert!(!quota.is_unlimited());
+    assert_eq!(quota.used(), 4);
+    assert_eq!(quota.remaining(), Some(6));
+    assert!(!quota.would_exceed(6));
+    assert!(quota.would_exceed(7));
+    assert_eq!(quota.utilisation(), Some(0.4));
+}
+
+#[test]
+fn unlimited_quotas() {
+    let quota = quota(-1, 1000, 10);
+    assert!(quota.is_unlimited());
+    assert_eq!(quota.remaining(), None);
+    assert!(!quota.would_exceed(u32::MAX));
+    assert_eq!(quota.utilisation(), None);
+}
+
+#[test]
+fn activation_uses_are_a_lower_bound_of_the_used_uses() {
+    let quota = quota(10, 2, 5);
+    assert_eq!(quota.used(), 5);
+    assert_eq!(quota.remaining(), Some(5));
+    assert_eq!(quota.utilisation(), Some(0.5));
+}
+
+#[test]
+fn exhausted_quotas() {
+    assert_eq!(quota(10, 12, 0).remaining(), Some(0));
+    assert!(quota(10, 10, 0).would_exceed(1));
+    assert!(!quota(10, 10, 0).would_exceed(0));
+    assert_eq!(quota(0, 0, 0).utilisation(), Some(1.0));
+    assert_eq!(quota(10, 15, 0).utilisation(), Some(1.5));
+}
+
+#[test]
+fn quotas_are_read_from_the_activation() {
+    let _guard = common::lock();
+    let mut license = common::license_with_meter("exports", 10, 3);
+    license.meter_attributes.extend(common::license_with_meter("api_calls", -1, 0).meter_attributes);
+    common::activate(license);
+    increment_activation_meter_attribute_uses("exports".to_string(), 2).unwrap();
+
+    let quotas = get_meter_quotas(["exports", "api_calls"]).unwrap();
+    assert_eq!(quotas[0].total_uses, 5);
+    assert_eq!(quotas[0].activation_uses, 2);
+    assert_eq!(quotas[0].remaining(), Some(5));
+    assert!(quotas[1].is_unlimited());
+    assert_eq!(get_meter_quota("missing").unwrap_err(), LexActivatorError::LA_E_METER_ATTRIBUTE_NOT_FOUND);
+}
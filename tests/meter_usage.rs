@@ -0,0 +1,95 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use lexactivator::*;
+
+fn activation_uses(name: &str) -> u32 {
+    get_activation_meter_attribute_uses(name.to_string()).unwrap()
+}
+
+#[test]
+fn uses_are_returned_on_drop() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("active_sessions", 5, 0));
+
+    let session = MeterUsageGuard::acquire("active_sessions", 2).unwrap();
+    assert_eq!(session.uses(), 2);
+    assert_eq!(activation_uses("active_sessions"), 2);
+    drop(session);
+    assert_eq!(activation_uses("active_sessions"), 0);
+}
+
+#[test]
+fn committed_uses_are_kept() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("exports", 5, 0));
+
+    MeterUsageGuard::acquire("exports", 1).unwrap().commit();
+    assert_eq!(activation_uses("exports"), 1);
+}
+
+#[test]
+fn released_uses_are_returned_once() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("exports", 5, 0));
+
+    let first = MeterUsageGuard::acquire("exports", 1).unwrap();
+    let second = MeterUsageGuard::acquire("exports", 1).unwrap();
+    first.release().unwrap();
+    assert_eq!(activation_uses("exports"), 1);
+    drop(second);
+    assert_eq!(activation_uses("exports"), 0);
+}
+
+#[test]
+fn acquiring_beyond_the_allowed_uses_fails() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("active_sessions", 3, 2));
+
+    let result = MeterUsageGuard::acquire("active_sessions", 2);
+    assert_eq!(result.unwrap_err(), LexActivatorError::LA_E_METER_ATTRIBUTE_USES_LIMIT_REACHED);
+    assert_eq!(activation_uses("active_sessions"), 0);
+}
+
+#[test]
+fn failed_releases_are_reported() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("active_sessions", 5, 0));
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&failures);
+
+    let session = MeterUsageGuard::acquire("active_sessions", 1)
+        .unwrap()
+        .on_release_error(move |operation, error| recorded.lock().unwrap().push((operation, error.to_string())));
+    stub::set_network_available(false);
+    drop(session);
+
+    let failures = failures.lock().unwrap();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0.kind, MeterOperationKind::Decrement);
+    assert_eq!(failures[0].1, LexActivatorError::LA_E_INET.to_string());
+}
+
+#[test]
+fn queued_releases_are_replayed() {
+    let _guard = common::lock();
+    common::activate(common::license_with_meter("active_sessions", 5, 0));
+    let queue = MeterQueue::open(common::temp_dir("meter-usage")).unwrap();
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&failures);
+
+    let session = MeterUsageGuard::acquire_queued(&queue, "active_sessions", 2)
+        .unwrap()
+        .on_release_error(move |operation, _| recorded.lock().unwrap().push(operation));
+    stub::set_network_available(false);
+    drop(session);
+    assert!(failures.lock().unwrap().is_empty());
+    assert_eq!(queue.pending().len(), 1);
+
+    stub::set_network_available(true);
+    queue.replay().unwrap();
+    assert_eq!(activation_uses("active_sessions"), 0);
+}
@@ -0,0 +1,64 @@
+#![cfg(all(feature = "stub", feature = "tokio"))]
+
+mod common;
+
+use lexactivator::*;
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(future)
+}
+
+#[test]
+fn async_functions_return_the_results_of_the_blocking_functions() {
+    let _guard = common::lock();
+    stub::set_license(common::license());
+    set_product_data("PRODUCT_DATA".to_string()).unwrap();
+    set_product_id("PRODUCT_ID".to_string(), PermissionFlags::LA_USER).unwrap();
+    set_license_key(common::LICENSE_KEY.to_string()).unwrap();
+
+    let results = block_on(async {
+        let activation = nonblocking::activate_license().await;
+        let genuine = nonblocking::is_license_genuine().await;
+        (activation, genuine)
+    });
+    assert_eq!(results, (Ok(LexActivatorStatus::LA_OK), Ok(LexActivatorStatus::LA_OK)));
+}
+
+#[test]
+fn async_functions_return_the_errors_of_the_blocking_functions() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    stub::set_network_available(false);
+
+    assert_eq!(block_on(nonblocking::sync_license_activation()), Err(LexActivatorError::LA_E_INET));
+}
+
+#[test]
+fn license_callback_codes_arrive_on_the_stream() {
+    use std::future::poll_fn;
+    use std::pin::Pin;
+
+    use futures_core::Stream;
+
+    let _guard = common::lock();
+    common::activate(common::license());
+    let subscribers = license_callback_subscriber_count();
+
+    let mut stream = nonblocking::license_callback_stream().unwrap();
+    assert_eq!(license_callback_subscriber_count(), subscribers + 1);
+    stub::invoke_license_callback(LexActivatorStatus::LA_EXPIRED.code());
+    stub::invoke_license_callback(LexActivatorError::LA_E_INET.code());
+
+    let codes = block_on(async {
+        let first = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await;
+        let second = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await;
+        (first, second)
+    });
+    assert_eq!(
+        codes,
+        (Some(LexActivatorCode::Status(LexActivatorStatus::LA_EXPIRED)), Some(LexActivatorCode::Error(LexActivatorError::LA_E_INET)))
+    );
+
+    drop(stream);
+    assert_eq!(license_callback_subscriber_count(), subscribers);
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use lexactivator::*;
+
+#[test]
+fn attributes_that_do_not_apply_are_none() {
+    let _guard = common::lock();
+    let mut license = common::license();
+    license.expiry_date = 0;
+    license.user_email = "user@example.com".to_string();
+    common::activate(license);
+
+    let snapshot = get_license_snapshot().unwrap();
+    assert_eq!(snapshot.license_key.as_deref(), Some(common::LICENSE_KEY));
+    assert_eq!(snapshot.user_email.as_deref(), Some("user@example.com"));
+    assert_eq!(snapshot.expiry_date, None);
+    assert_eq!(snapshot.entitlement_set_name, None);
+    assert!(snapshot.feature_entitlements.is_none());
+    assert_eq!(snapshot.trial_id, None);
+}
+
+#[test]
+fn attributes_of_a_license_that_is_not_activated_are_none() {
+    let _guard = common::lock();
+    stub::set_license(common::license());
+    set_product_data("PRODUCT_DATA".to_string()).unwrap();
+    set_product_id("PRODUCT_ID".to_string(), PermissionFlags::LA_USER).unwrap();
+
+    let snapshot = get_license_snapshot().unwrap();
+    assert_eq!(snapshot.license_key, None);
+    assert_eq!(snapshot.activation_id, None);
+    assert!(snapshot.library_version.is_some());
+}
+
+#[test]
+fn setup_errors_are_returned() {
+    let _guard = common::lock();
+
+    assert_eq!(get_license_snapshot().unwrap_err(), LexActivatorError::LA_E_PRODUCT_DATA);
+}
+
+#[test]
+fn json_parse_errors_are_returned() {
+    let _guard = common::lock();
+    let mut license = common::license();
+    license.entitlement_set_name = "pro".to_string();
+    license.feature_entitlements_json = "not json".to_string();
+    common::activate(license);
+
+    assert!(matches!(get_license_snapshot(), Err(LexActivatorError::JsonParse(_))));
+}
+
+#[test]
+fn other_errors_of_the_backend_are_returned() {
+    /// An activated license whose type cannot be read.
+    struct FailingBackend(LexActivatorError);
+
+    impl LicensingBackend for FailingBackend {
+        fn is_license_valid(&self) -> Result<LexActivatorStatus, LexActivatorError> {
+            Ok(LexActivatorStatus::LA_OK)
+        }
+
+        fn get_license_key(&self) -> Result<String, LexActivatorError> {
+            Ok(common::LICENSE_KEY.to_string())
+        }
+
+        fn get_license_type(&self) -> Result<String, LexActivatorError> {
+            Err(self.0.clone())
+        }
+    }
+
+    for error in [LexActivatorError::LA_E_TIME_MODIFIED, LexActivatorError::LA_FAIL] {
+        assert_eq!(LicenseSnapshot::capture(&FailingBackend(error.clone())).unwrap_err(), error);
+    }
+}
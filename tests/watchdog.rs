@@ -0,0 +1,107 @@
+#![cfg(feature = "stub")]
+
+mod common;
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use lexactivator::*;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[test]
+fn the_first_check_is_published() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let (sender, receiver) = mpsc::channel();
+
+    let watchdog = LicenseWatchdog::builder().transition_channel(sender).start().unwrap();
+    assert_eq!(watchdog.access(), LicenseAccess::Full);
+    assert_eq!(watchdog.last_result(), Some(Ok(LexActivatorStatus::LA_OK)));
+
+    let transition = receiver.recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(transition.previous, None);
+    assert_eq!(transition.previous_access, None);
+    assert_eq!(transition.current, Ok(LexActivatorStatus::LA_OK));
+    assert_eq!(transition.access, LicenseAccess::Full);
+    watchdog.stop();
+}
+
+#[test]
+fn changes_of_the_license_are_published() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let (sender, receiver) = mpsc::channel();
+    let watchdog = LicenseWatchdog::builder().interval(Duration::ZERO).transition_channel(sender).start().unwrap();
+    receiver.recv_timeout(TIMEOUT).unwrap();
+
+    stub::update_license(|license| license.suspended = true);
+    let transition = receiver.recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(transition.previous, Some(Ok(LexActivatorStatus::LA_OK)));
+    assert_eq!(transition.current, Ok(LexActivatorStatus::LA_SUSPENDED));
+    assert_eq!(transition.previous_access, Some(LicenseAccess::Full));
+    assert_eq!(transition.access, LicenseAccess::Denied);
+    assert_eq!(watchdog.access(), LicenseAccess::Denied);
+    watchdog.stop();
+}
+
+#[test]
+fn unchanged_results_are_not_published() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let (sender, receiver) = mpsc::channel();
+    let watchdog = LicenseWatchdog::builder().interval(Duration::ZERO).transition_channel(sender).start().unwrap();
+    receiver.recv_timeout(TIMEOUT).unwrap();
+
+    assert_eq!(receiver.recv_timeout(Duration::from_millis(2500)), Err(mpsc::RecvTimeoutError::Timeout));
+    watchdog.stop();
+}
+
+#[test]
+fn the_local_result_is_used_while_offline() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    stub::set_network_available(false);
+
+    let watchdog = LicenseWatchdog::builder().start().unwrap();
+    assert_eq!(watchdog.last_result(), Some(Ok(LexActivatorStatus::LA_OK)));
+    assert_eq!(watchdog.access(), LicenseAccess::Full);
+}
+
+#[test]
+fn the_policy_decides_the_access() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    stub::update_license(|license| license.suspended = true);
+
+    let watchdog = LicenseWatchdog::builder()
+        .policy(|result| match result {
+            Ok(LexActivatorStatus::LA_SUSPENDED) => LicenseAccess::ReadOnly,
+            result => default_license_access_policy(result),
+        })
+        .start()
+        .unwrap();
+    assert_eq!(watchdog.access(), LicenseAccess::ReadOnly);
+}
+
+#[test]
+fn a_panicking_policy_denies_access() {
+    let _guard = common::lock();
+    common::activate(common::license());
+    let (sender, receiver) = mpsc::channel();
+    set_license_callback_panic_handler(move |message| {
+        let _ = sender.send(message);
+    });
+
+    let watchdog = LicenseWatchdog::builder().policy(|_| panic!("policy failed")).start().unwrap();
+    assert_eq!(watchdog.access(), LicenseAccess::Denied);
+    assert_eq!(watchdog.last_result(), Some(Ok(LexActivatorStatus::LA_OK)));
+    assert_eq!(receiver.recv_timeout(TIMEOUT).unwrap(), "policy failed");
+    set_license_callback_panic_handler(|_| {});
+}
+
+#[test]
+fn short_intervals_are_raised_to_the_minimum() {
+    let builder = LicenseWatchdog::builder().interval(Duration::ZERO);
+    assert!(format!("{:?}", builder).contains(&format!("{:?}", MIN_WATCHDOG_INTERVAL)));
+}